@@ -0,0 +1,125 @@
+//! Derive macro backing the `derive` feature of `datetime-default`.
+//!
+//! `#[derive(DefaultNow)]` implements `Default` for a struct. Fields annotated
+//! with `#[datetime_default]` must be `DateTime<Tz>` and default to the current time
+//! through `DateTimeDefaultNow`; every other field uses its own `Default`.
+//!
+//! ```ignore
+//! use chrono::{DateTime, Utc};
+//! use datetime_default::DefaultNow;
+//!
+//! #[derive(DefaultNow)]
+//! struct Article {
+//!     title: String,
+//!     #[datetime_default]
+//!     created_at: DateTime<Utc>,
+//! }
+//! ```
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{quote, quote_spanned};
+use syn::{
+    parse_macro_input, spanned::Spanned, Data, DeriveInput, Field, Fields, GenericArgument,
+    PathArguments, Type,
+};
+
+const ATTRIBUTE: &str = "datetime_default";
+
+#[proc_macro_derive(DefaultNow, attributes(datetime_default))]
+pub fn derive_default_now(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "`DefaultNow` can only be derived for structs",
+            ))
+        }
+    };
+
+    let body = match fields {
+        Fields::Named(named) => {
+            let values = named
+                .named
+                .iter()
+                .map(|field| {
+                    let ident = &field.ident;
+                    let value = field_default(field)?;
+                    Ok(quote! { #ident: #value })
+                })
+                .collect::<syn::Result<Vec<_>>>()?;
+            quote! { Self { #(#values),* } }
+        }
+        Fields::Unnamed(unnamed) => {
+            let values = unnamed
+                .unnamed
+                .iter()
+                .map(field_default)
+                .collect::<syn::Result<Vec<_>>>()?;
+            quote! { Self(#(#values),*) }
+        }
+        Fields::Unit => quote! { Self },
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::core::default::Default for #name #ty_generics #where_clause {
+            fn default() -> Self {
+                #body
+            }
+        }
+    })
+}
+
+fn field_default(field: &Field) -> syn::Result<TokenStream2> {
+    let mut annotated = false;
+    for attr in &field.attrs {
+        if attr.path().is_ident(ATTRIBUTE) {
+            attr.meta.require_path_only()?;
+            annotated = true;
+        }
+    }
+
+    if !annotated {
+        return Ok(quote_spanned! { field.ty.span()=> ::core::default::Default::default() });
+    }
+
+    let tz = timezone_of(&field.ty).ok_or_else(|| {
+        syn::Error::new_spanned(
+            &field.ty,
+            "`#[datetime_default]` fields must be of type `DateTime<Tz>`",
+        )
+    })?;
+    Ok(quote_spanned! { field.ty.span()=>
+        ::core::convert::Into::into(
+            <::datetime_default::DateTimeDefaultNow<#tz> as ::core::default::Default>::default()
+        )
+    })
+}
+
+/// Extracts `Tz` from a `DateTime<Tz>` path, however it is qualified.
+fn timezone_of(ty: &Type) -> Option<&Type> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    if segment.ident != "DateTime" {
+        return None;
+    }
+    let PathArguments::AngleBracketed(arguments) = &segment.arguments else {
+        return None;
+    };
+    arguments.args.iter().find_map(|argument| match argument {
+        GenericArgument::Type(tz) => Some(tz),
+        _ => None,
+    })
+}
@@ -0,0 +1,46 @@
+//! Compile check for the `no_std` build:
+//!
+//! ```sh
+//! cargo build --example no_std --no-default-features
+//! ```
+#![no_std]
+
+use chrono::{DateTime, Duration, FixedOffset, NaiveDate, NaiveDateTime, Utc};
+use datetime_default::{
+    DateDefaultToday, DateTimeDefaultEpoch, DateTimeDefaultMax, DateTimeDefaultMin,
+    DateTimeDefaultNow, DateTimeDefaultNowMinutes, DateTimeDefaultNowSecs, DateTimeDefaultUnix,
+    NaiveDateTimeDefaultNow,
+};
+
+pub static EPOCH: DateTimeDefaultEpoch<Utc> = DateTimeDefaultEpoch::<Utc>::VALUE;
+
+pub fn defaults() -> (
+    DateTimeDefaultUnix<FixedOffset, 9>,
+    DateTimeDefaultMin<Utc>,
+    DateTimeDefaultMax<Utc>,
+) {
+    Default::default()
+}
+
+pub fn shifted(datetime: DateTime<Utc>) -> DateTimeDefaultNow<FixedOffset, 9> {
+    DateTimeDefaultNow::<Utc>::from(datetime)
+        .saturating_add(Duration::hours(1))
+        .to_fixed::<9>()
+}
+
+pub fn india(
+    datetime: DateTime<Utc>,
+) -> (
+    DateTimeDefaultNowMinutes<330>,
+    DateTimeDefaultNowSecs<19800>,
+) {
+    (datetime.into(), datetime.into())
+}
+
+pub fn naive(datetime: NaiveDateTime) -> bool {
+    NaiveDateTimeDefaultNow::from(datetime) <= datetime
+}
+
+pub fn date(date: NaiveDate) -> DateDefaultToday<Utc> {
+    DateDefaultToday::from(date)
+}
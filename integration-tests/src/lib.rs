@@ -0,0 +1 @@
+//! Integration tests needing database drivers, enabled by this crate's features.
@@ -0,0 +1,36 @@
+#![cfg(feature = "diesel")]
+
+use std::num::NonZeroU32;
+
+use chrono::Utc;
+use datetime_default::DateTimeDefaultNow;
+use diesel_2::{
+    deserialize::FromSql,
+    pg::{Pg, PgMetadataLookup, PgTypeMetadata, PgValue},
+    query_builder::{bind_collector::RawBytesBindCollector, BindCollector},
+    sql_types::Timestamptz,
+};
+
+struct NoLookup;
+
+impl PgMetadataLookup for NoLookup {
+    fn lookup_type(&mut self, _: &str, _: Option<&str>) -> PgTypeMetadata {
+        unreachable!("Timestamptz has a static oid")
+    }
+}
+
+#[test]
+fn roundtrip_bytes() {
+    let datetime = DateTimeDefaultNow::<Utc>::from_timestamp(1665445211, 0).unwrap();
+    let mut collector = RawBytesBindCollector::<Pg>::new();
+    collector
+        .push_bound_value::<Timestamptz, _>(&datetime, &mut NoLookup)
+        .unwrap();
+    let bytes = collector.binds[0].as_ref().unwrap();
+    let oid = NonZeroU32::new(1184).unwrap();
+
+    assert_eq!(
+        DateTimeDefaultNow::<Utc>::from_sql(PgValue::new(bytes, &oid)).unwrap(),
+        datetime
+    )
+}
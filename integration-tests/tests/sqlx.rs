@@ -0,0 +1,35 @@
+#![cfg(feature = "sqlx")]
+
+use chrono::{DateTime, Utc};
+use datetime_default::DateTimeDefaultNow;
+use sqlx_08::{
+    postgres::{PgArgumentBuffer, PgTypeInfo},
+    Encode, Postgres, Type,
+};
+
+#[test]
+fn type_info_timestamptz() {
+    assert_eq!(
+        <DateTimeDefaultNow<Utc> as Type<Postgres>>::type_info(),
+        PgTypeInfo::with_name("TIMESTAMPTZ")
+    );
+    assert!(<DateTimeDefaultNow<Utc> as Type<Postgres>>::compatible(
+        &<DateTime<Utc> as Type<Postgres>>::type_info()
+    ))
+}
+
+#[test]
+fn encode_as_inner() {
+    let datetime = DateTimeDefaultNow::<Utc>::from_timestamp(1665445211, 695_164_300).unwrap();
+    let mut wrapped = PgArgumentBuffer::default();
+    let mut inner = PgArgumentBuffer::default();
+
+    assert!(!Encode::<Postgres>::encode_by_ref(&datetime, &mut wrapped)
+        .unwrap()
+        .is_null());
+    assert!(!Encode::<Postgres>::encode_by_ref(&*datetime, &mut inner)
+        .unwrap()
+        .is_null());
+
+    assert_eq!(&**wrapped, &**inner)
+}
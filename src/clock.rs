@@ -0,0 +1,178 @@
+use std::cell::{Cell, RefCell};
+
+#[cfg(test)]
+use chrono::NaiveDateTime;
+use chrono::{DateTime, Utc};
+
+thread_local! {
+    static TEST_CLOCK: RefCell<Option<Box<dyn Clock>>> = const { RefCell::new(None) };
+}
+
+/// # Source of the current time.
+///
+/// `DateTimeDefaultNow` asks the clock registered with [`set_test_clock`] for "now",
+/// and falls back to [`SystemClock`] when none is registered.
+pub trait Clock {
+    /// Returns the current instant in UTC.
+    fn now_utc(&self) -> DateTime<Utc>;
+}
+
+/// # Clock reading the system time.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now_utc(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// # Clock pinned to a fixed instant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FixedClock(pub DateTime<Utc>);
+
+impl Clock for FixedClock {
+    fn now_utc(&self) -> DateTime<Utc> {
+        self.0
+    }
+}
+
+/// # Clock that never goes backwards.
+///
+/// Wraps another clock and remembers the last instant it returned,
+/// returning that instant again whenever the inner clock reads earlier.
+#[derive(Debug, Clone, Default)]
+pub struct MonotonicClock<C> {
+    inner: C,
+    last: Cell<Option<DateTime<Utc>>>,
+}
+
+impl<C: Clock> MonotonicClock<C> {
+    /// Wraps `inner`, which has not returned any instant yet.
+    pub fn new(inner: C) -> Self {
+        Self {
+            inner,
+            last: Cell::new(None),
+        }
+    }
+}
+
+impl<C: Clock> Clock for MonotonicClock<C> {
+    fn now_utc(&self) -> DateTime<Utc> {
+        let now = match self.last.get() {
+            Some(last) => self.inner.now_utc().max(last),
+            None => self.inner.now_utc(),
+        };
+        self.last.set(Some(now));
+        now
+    }
+}
+
+/// Overrides the clock used for "now" on the current thread.
+///
+/// ```
+/// use chrono::{DateTime, Utc};
+/// use datetime_default::{clear_test_clock, set_test_clock, DateTimeDefaultNow, FixedClock};
+///
+/// let now = DateTime::parse_from_rfc3339("2022-10-10T23:40:11Z").unwrap().with_timezone(&Utc);
+///
+/// set_test_clock(FixedClock(now));
+/// assert_eq!(DateTimeDefaultNow::<Utc>::default(), now);
+///
+/// clear_test_clock();
+/// assert_ne!(DateTimeDefaultNow::<Utc>::default(), now);
+/// ```
+pub fn set_test_clock(clock: impl Clock + 'static) {
+    TEST_CLOCK.with(|test_clock| *test_clock.borrow_mut() = Some(Box::new(clock)));
+}
+
+/// Removes the clock registered with [`set_test_clock`] on the current thread.
+pub fn clear_test_clock() {
+    TEST_CLOCK.with(|test_clock| *test_clock.borrow_mut() = None);
+}
+
+pub(crate) fn test_clock_now() -> Option<DateTime<Utc>> {
+    TEST_CLOCK.with(|test_clock| test_clock.borrow().as_ref().map(|clock| clock.now_utc()))
+}
+
+/// Wall-clock time every "now" is pinned to in the crate's own tests,
+/// read as UTC or local time depending on the timezone.
+#[cfg(test)]
+pub(crate) fn test_mode_now() -> NaiveDateTime {
+    NaiveDateTime::parse_from_str("2022/10/10 23:40:11.695164300", "%Y/%m/%d %H:%M:%S%.9f").unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use chrono::{DateTime, Duration, FixedOffset, Local, Utc};
+
+    use crate::{
+        clear_test_clock, set_test_clock, Clock, DateTimeDefaultNow, FixedClock, MonotonicClock,
+    };
+
+    fn instant() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2000-01-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    #[test]
+    fn fixed_clock() {
+        set_test_clock(FixedClock(instant()));
+
+        assert_eq!(
+            DateTimeDefaultNow::<Utc>::default(),
+            DateTimeDefaultNow::<Utc>::default()
+        );
+        assert_eq!(DateTimeDefaultNow::<Utc>::default(), instant());
+
+        clear_test_clock();
+    }
+
+    #[test]
+    fn fixed_clock_timezones() {
+        set_test_clock(FixedClock(instant()));
+
+        assert_eq!(*DateTimeDefaultNow::<Local>::default(), instant());
+        assert_eq!(*DateTimeDefaultNow::<FixedOffset, 9>::default(), instant());
+
+        clear_test_clock();
+    }
+
+    /// Clock stepping back one second on every read.
+    struct RegressingClock(Cell<DateTime<Utc>>);
+
+    impl Clock for RegressingClock {
+        fn now_utc(&self) -> DateTime<Utc> {
+            let now = self.0.get();
+            self.0.set(now - Duration::seconds(1));
+            now
+        }
+    }
+
+    #[test]
+    fn monotonic_clock() {
+        set_test_clock(MonotonicClock::new(RegressingClock(Cell::new(instant()))));
+
+        let first = DateTimeDefaultNow::<Utc>::now();
+        let second = DateTimeDefaultNow::<Utc>::now();
+
+        assert!(second >= first);
+        assert_eq!(first, instant());
+        assert_eq!(second, instant());
+
+        clear_test_clock();
+    }
+
+    #[test]
+    fn clear_clock() {
+        let before = DateTimeDefaultNow::<Utc>::default();
+
+        set_test_clock(FixedClock(instant()));
+        clear_test_clock();
+
+        assert_eq!(DateTimeDefaultNow::<Utc>::default(), before);
+    }
+}
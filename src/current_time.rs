@@ -0,0 +1,173 @@
+use chrono::{DateTime, TimeZone};
+#[cfg(feature = "std")]
+use chrono::{Local, Utc};
+
+#[cfg(feature = "std")]
+use crate::DateTimeDefaultNow;
+
+/// # Timezone able to tell the current time.
+///
+/// `DateTimeDefaultNow<Tz>` implements `Default` for every `Tz` implementing this trait,
+/// so code generic over the timezone can still derive `Default`.
+/// `Utc` and `Local` are provided with the `std` feature; implement it to make a custom timezone defaultable.
+///
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use chrono::{DateTime, FixedOffset, LocalResult, NaiveDate, NaiveDateTime, Offset, TimeZone};
+/// use datetime_default::{CurrentTime, DateTimeDefaultNow};
+///
+/// #[derive(Debug, Clone, Copy)]
+/// struct Tokyo;
+///
+/// impl Offset for Tokyo {
+///     fn fix(&self) -> FixedOffset {
+///         FixedOffset::east_opt(9 * 3600).unwrap()
+///     }
+/// }
+///
+/// impl TimeZone for Tokyo {
+///     type Offset = Tokyo;
+///
+///     fn from_offset(_: &Tokyo) -> Self {
+///         Tokyo
+///     }
+///     fn offset_from_local_date(&self, _: &NaiveDate) -> LocalResult<Tokyo> {
+///         LocalResult::Single(Tokyo)
+///     }
+///     fn offset_from_local_datetime(&self, _: &NaiveDateTime) -> LocalResult<Tokyo> {
+///         LocalResult::Single(Tokyo)
+///     }
+///     fn offset_from_utc_date(&self, _: &NaiveDate) -> Tokyo {
+///         Tokyo
+///     }
+///     fn offset_from_utc_datetime(&self, _: &NaiveDateTime) -> Tokyo {
+///         Tokyo
+///     }
+/// }
+///
+/// impl CurrentTime for Tokyo {
+///     fn now() -> DateTime<Self> {
+///         chrono::Utc::now().with_timezone(&Tokyo)
+///     }
+/// }
+///
+/// let datetime = DateTimeDefaultNow::<Tokyo>::default();
+/// assert_eq!(datetime.offset().fix(), FixedOffset::east_opt(9 * 3600).unwrap());
+/// # }
+/// ```
+pub trait CurrentTime: TimeZone {
+    /// Returns the current time in this timezone.
+    fn now() -> DateTime<Self>;
+}
+
+#[cfg(feature = "std")]
+impl CurrentTime for Utc {
+    fn now() -> DateTime<Self> {
+        DateTimeDefaultNow::<Utc>::now().into_inner()
+    }
+}
+
+#[cfg(feature = "std")]
+impl CurrentTime for Local {
+    fn now() -> DateTime<Self> {
+        DateTimeDefaultNow::<Local>::now().into_inner()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use chrono::{
+        DateTime, FixedOffset, LocalResult, NaiveDate, NaiveDateTime, Offset, TimeZone, Utc,
+    };
+
+    use crate::{CurrentTime, DateTimeDefaultNow};
+
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+    struct Zero;
+
+    impl Offset for Zero {
+        fn fix(&self) -> FixedOffset {
+            FixedOffset::east_opt(0).unwrap()
+        }
+    }
+
+    impl TimeZone for Zero {
+        type Offset = Zero;
+
+        fn from_offset(_: &Zero) -> Self {
+            Zero
+        }
+
+        fn offset_from_local_date(&self, _: &NaiveDate) -> LocalResult<Zero> {
+            LocalResult::Single(Zero)
+        }
+
+        fn offset_from_local_datetime(&self, _: &NaiveDateTime) -> LocalResult<Zero> {
+            LocalResult::Single(Zero)
+        }
+
+        fn offset_from_utc_date(&self, _: &NaiveDate) -> Zero {
+            Zero
+        }
+
+        fn offset_from_utc_datetime(&self, _: &NaiveDateTime) -> Zero {
+            Zero
+        }
+    }
+
+    impl CurrentTime for Zero {
+        fn now() -> DateTime<Self> {
+            <Utc as CurrentTime>::now().with_timezone(&Zero)
+        }
+    }
+
+    #[derive(Default)]
+    struct Test<Tz>
+    where
+        Tz: CurrentTime,
+        <Tz as TimeZone>::Offset: Copy,
+    {
+        updated_at: DateTimeDefaultNow<Tz>,
+    }
+
+    fn default_in<Tz>() -> DateTimeDefaultNow<Tz>
+    where
+        Tz: CurrentTime,
+        <Tz as TimeZone>::Offset: Copy,
+    {
+        DateTimeDefaultNow::default()
+    }
+
+    #[test]
+    fn default_custom_timezone() {
+        let datetime = default_in::<Zero>();
+
+        assert_eq!(datetime.offset(), &Zero);
+        assert_eq!(
+            datetime.timestamp(),
+            DateTimeDefaultNow::<Utc>::default().timestamp()
+        )
+    }
+
+    #[test]
+    fn default_derive_generic_struct() {
+        let test = Test::<Zero>::default();
+
+        assert_eq!(
+            test.updated_at.naive_utc(),
+            DateTimeDefaultNow::<Utc>::default().naive_utc()
+        )
+    }
+
+    #[test]
+    fn provided_timezones() {
+        assert_eq!(
+            <Utc as CurrentTime>::now(),
+            DateTimeDefaultNow::<Utc>::now()
+        );
+        assert_eq!(
+            <chrono::Local as CurrentTime>::now(),
+            *DateTimeDefaultNow::<chrono::Local>::now()
+        )
+    }
+}
@@ -0,0 +1,308 @@
+use core::{marker::PhantomData, ops::Deref};
+
+#[cfg(feature = "std")]
+use chrono::{FixedOffset, Local, Utc};
+use chrono::{NaiveDate, TimeZone};
+
+#[cfg(feature = "std")]
+use crate::DateTimeDefaultNow;
+
+/// # Date with today as default.
+///
+/// Today is the calendar date of the current time in `Tz`.
+///
+/// ```should_panic
+/// # #[cfg(feature = "std")] {
+/// use chrono::{NaiveDate, Utc};
+/// use datetime_default::DateDefaultToday;
+///
+/// assert_eq!(
+///     DateDefaultToday::<Utc>::default(),
+///     NaiveDate::from_ymd_opt(2022, 10, 10).unwrap()
+/// );
+/// # }
+/// # #[cfg(not(feature = "std"))]
+/// # panic!("needs the `std` feature");
+/// ```
+pub struct DateDefaultToday<Tz, const OFFSET_HOURS: i32 = 0>(NaiveDate, PhantomData<Tz>)
+where
+    Tz: TimeZone;
+
+#[cfg(feature = "std")]
+impl<const OFFSET_HOURS: i32> DateDefaultToday<FixedOffset, OFFSET_HOURS> {
+    /// Returns today's date at the configured offset, which can differ from the UTC date.
+    pub fn today() -> Self {
+        Self::from(DateTimeDefaultNow::<FixedOffset, OFFSET_HOURS>::now().date_naive())
+    }
+}
+
+#[cfg(feature = "std")]
+impl DateDefaultToday<Local, 0> {
+    /// Returns today's date in the local timezone.
+    pub fn today() -> Self {
+        Self::from(DateTimeDefaultNow::<Local>::now().date_naive())
+    }
+}
+
+#[cfg(feature = "std")]
+impl DateDefaultToday<Utc, 0> {
+    /// Returns today's date in UTC.
+    pub fn today() -> Self {
+        Self::from(DateTimeDefaultNow::<Utc>::now().date_naive())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<const OFFSET_HOURS: i32> Default for DateDefaultToday<FixedOffset, OFFSET_HOURS> {
+    fn default() -> Self {
+        Self::today()
+    }
+}
+
+#[cfg(feature = "std")]
+impl Default for DateDefaultToday<Local, 0> {
+    fn default() -> Self {
+        Self::today()
+    }
+}
+
+#[cfg(feature = "std")]
+impl Default for DateDefaultToday<Utc, 0> {
+    fn default() -> Self {
+        Self::today()
+    }
+}
+
+impl<Tz, const OFFSET_HOURS: i32> core::fmt::Debug for DateDefaultToday<Tz, OFFSET_HOURS>
+where
+    Tz: TimeZone,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.debug_tuple("DateDefaultToday").field(&self.0).finish()
+    }
+}
+
+impl<Tz, const OFFSET_HOURS: i32> Clone for DateDefaultToday<Tz, OFFSET_HOURS>
+where
+    Tz: TimeZone,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Tz, const OFFSET_HOURS: i32> Copy for DateDefaultToday<Tz, OFFSET_HOURS> where Tz: TimeZone {}
+
+impl<Tz, const OFFSET_HOURS: i32> core::cmp::PartialEq for DateDefaultToday<Tz, OFFSET_HOURS>
+where
+    Tz: TimeZone,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.0.eq(&other.0)
+    }
+}
+
+impl<Tz, const OFFSET_HOURS: i32> core::cmp::Eq for DateDefaultToday<Tz, OFFSET_HOURS> where
+    Tz: TimeZone
+{
+}
+
+impl<Tz, const OFFSET_HOURS: i32> core::cmp::PartialOrd for DateDefaultToday<Tz, OFFSET_HOURS>
+where
+    Tz: TimeZone,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<Tz, const OFFSET_HOURS: i32> core::cmp::Ord for DateDefaultToday<Tz, OFFSET_HOURS>
+where
+    Tz: TimeZone,
+{
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+impl<Tz, const OFFSET_HOURS: i32> core::hash::Hash for DateDefaultToday<Tz, OFFSET_HOURS>
+where
+    Tz: TimeZone,
+{
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.0.hash(state)
+    }
+}
+
+impl<Tz, const OFFSET_HOURS: i32> Deref for DateDefaultToday<Tz, OFFSET_HOURS>
+where
+    Tz: TimeZone,
+{
+    type Target = NaiveDate;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<Tz, const OFFSET_HOURS: i32> From<NaiveDate> for DateDefaultToday<Tz, OFFSET_HOURS>
+where
+    Tz: TimeZone,
+{
+    fn from(date: NaiveDate) -> Self {
+        Self(date, PhantomData)
+    }
+}
+
+impl<Tz, const OFFSET_HOURS: i32> From<DateDefaultToday<Tz, OFFSET_HOURS>> for NaiveDate
+where
+    Tz: TimeZone,
+{
+    fn from(date: DateDefaultToday<Tz, OFFSET_HOURS>) -> Self {
+        date.0
+    }
+}
+
+impl<Tz, const OFFSET_HOURS: i32> core::cmp::PartialEq<NaiveDate>
+    for DateDefaultToday<Tz, OFFSET_HOURS>
+where
+    Tz: TimeZone,
+{
+    fn eq(&self, other: &NaiveDate) -> bool {
+        self.0.eq(other)
+    }
+}
+
+impl<Tz, const OFFSET_HOURS: i32> core::cmp::PartialEq<DateDefaultToday<Tz, OFFSET_HOURS>>
+    for NaiveDate
+where
+    Tz: TimeZone,
+{
+    fn eq(&self, other: &DateDefaultToday<Tz, OFFSET_HOURS>) -> bool {
+        self.eq(&other.0)
+    }
+}
+
+impl<Tz, const OFFSET_HOURS: i32> core::cmp::PartialOrd<NaiveDate>
+    for DateDefaultToday<Tz, OFFSET_HOURS>
+where
+    Tz: TimeZone,
+{
+    fn partial_cmp(&self, other: &NaiveDate) -> Option<core::cmp::Ordering> {
+        self.0.partial_cmp(other)
+    }
+}
+
+impl<Tz, const OFFSET_HOURS: i32> core::cmp::PartialOrd<DateDefaultToday<Tz, OFFSET_HOURS>>
+    for NaiveDate
+where
+    Tz: TimeZone,
+{
+    fn partial_cmp(
+        &self,
+        other: &DateDefaultToday<Tz, OFFSET_HOURS>,
+    ) -> Option<core::cmp::Ordering> {
+        self.partial_cmp(&other.0)
+    }
+}
+
+impl<Tz, const OFFSET_HOURS: i32> core::fmt::Display for DateDefaultToday<Tz, OFFSET_HOURS>
+where
+    Tz: TimeZone,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+
+    use chrono::{DateTime, FixedOffset, Local, NaiveDate, Utc};
+
+    use crate::{
+        clear_test_clock, set_test_clock, DateDefaultToday, DateTimeDefaultNow, FixedClock,
+    };
+
+    #[derive(Default)]
+    struct Test {
+        valid_from: DateDefaultToday<Utc>,
+    }
+
+    #[test]
+    fn default_derive_struct() {
+        assert_eq!(
+            Test::default().valid_from,
+            NaiveDate::from_ymd_opt(2022, 10, 10).unwrap()
+        );
+    }
+
+    #[test]
+    fn utc() {
+        assert_eq!(
+            DateDefaultToday::<Utc>::default(),
+            DateTimeDefaultNow::<Utc>::default().date_naive()
+        );
+    }
+
+    #[test]
+    fn local() {
+        assert_eq!(
+            DateDefaultToday::<Local>::default(),
+            NaiveDate::from_ymd_opt(2022, 10, 10).unwrap()
+        );
+    }
+
+    #[test]
+    fn fixed_offset_date_boundary() {
+        let now = DateTime::parse_from_rfc3339("2022-10-10T23:40:11Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        set_test_clock(FixedClock(now));
+
+        assert_eq!(
+            DateDefaultToday::<Utc>::default(),
+            NaiveDate::from_ymd_opt(2022, 10, 10).unwrap()
+        );
+        assert_eq!(
+            DateDefaultToday::<FixedOffset, 9>::default(),
+            NaiveDate::from_ymd_opt(2022, 10, 11).unwrap()
+        );
+        assert_eq!(
+            DateDefaultToday::<FixedOffset, -5>::default(),
+            NaiveDate::from_ymd_opt(2022, 10, 10).unwrap()
+        );
+
+        clear_test_clock();
+    }
+
+    #[test]
+    fn stable_within_run() {
+        assert_eq!(
+            DateDefaultToday::<Utc>::default(),
+            DateDefaultToday::<Utc>::default()
+        );
+    }
+
+    #[test]
+    fn use_date_method() {
+        let date = DateDefaultToday::<Utc>::default();
+
+        assert_eq!(date.succ_opt(), NaiveDate::from_ymd_opt(2022, 10, 11));
+    }
+
+    #[test]
+    fn convert_into() {
+        let date: NaiveDate = DateDefaultToday::<Utc>::default().into();
+
+        assert_eq!(date, NaiveDate::from_ymd_opt(2022, 10, 10).unwrap());
+    }
+
+    #[test]
+    fn comparing() {
+        let date = DateDefaultToday::<Utc>::default();
+
+        assert!(date > NaiveDate::from_ymd_opt(2022, 10, 9).unwrap());
+        assert!(NaiveDate::from_ymd_opt(2022, 10, 11).unwrap() > date);
+    }
+}
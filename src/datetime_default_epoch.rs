@@ -0,0 +1,86 @@
+use crate::DateTimeDefaultUnix;
+
+/// # DateTime with UNIX epoch as default.
+///
+/// Alias of [`DateTimeDefaultUnix`], named after the conventional "zero" timestamp.
+///
+/// ```
+/// use chrono::{DateTime, FixedOffset, TimeZone, Utc};
+/// use datetime_default::DateTimeDefaultEpoch;
+///
+/// assert_eq!(
+///     DateTimeDefaultEpoch::<Utc>::default(),
+///     Utc.timestamp_opt(0, 0).unwrap()
+/// );
+/// assert_eq!(
+///     DateTimeDefaultEpoch::<FixedOffset, 9>::default(),
+///     DateTime::parse_from_rfc3339("1970-01-01T09:00:00+09:00").unwrap()
+/// );
+/// ```
+pub type DateTimeDefaultEpoch<Tz, const OFFSET_HOURS: i32 = 0> =
+    DateTimeDefaultUnix<Tz, OFFSET_HOURS>;
+
+#[cfg(test)]
+mod tests {
+
+    #[cfg(feature = "std")]
+    use chrono::Local;
+    use chrono::{DateTime, FixedOffset, TimeZone, Utc};
+
+    use crate::{DateTimeDefaultEpoch, DateTimeDefaultUnix};
+
+    #[derive(Default)]
+    struct Test {
+        updated_at: DateTimeDefaultEpoch<Utc>,
+    }
+
+    #[test]
+    fn default_derive_struct() {
+        assert_eq!(Test::default().updated_at, Utc.timestamp_opt(0, 0).unwrap());
+    }
+
+    #[test]
+    fn utc() {
+        assert_eq!(
+            DateTimeDefaultEpoch::<Utc>::default(),
+            Utc.timestamp_opt(0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn local() {
+        assert_eq!(
+            DateTimeDefaultEpoch::<Local>::default(),
+            Local.timestamp_opt(0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn fixed_offset() {
+        let datetime = DateTimeDefaultEpoch::<FixedOffset, 9>::default();
+
+        assert_eq!(datetime.to_rfc3339(), "1970-01-01T09:00:00+09:00");
+        assert_eq!(*datetime, DateTime::<Utc>::UNIX_EPOCH);
+    }
+
+    #[test]
+    fn same_as_unix() {
+        assert_eq!(
+            DateTimeDefaultEpoch::<FixedOffset, 9>::default(),
+            DateTimeDefaultUnix::<FixedOffset, 9>::default()
+        );
+    }
+
+    static EPOCHS: [DateTimeDefaultEpoch<Utc>; 2] = [
+        DateTimeDefaultEpoch::<Utc>::VALUE,
+        DateTimeDefaultEpoch::<Utc>::VALUE,
+    ];
+
+    #[test]
+    fn const_value_in_static_table() {
+        assert!(EPOCHS
+            .iter()
+            .all(|epoch| *epoch == Utc.timestamp_opt(0, 0).unwrap()))
+    }
+}
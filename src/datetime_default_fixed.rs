@@ -0,0 +1,190 @@
+use core::ops::Deref;
+
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+
+/// # DateTime with a compile-time fixed date as default.
+///
+/// The default is midnight UTC of `YEAR-MONTH-DAY`, the same in every build,
+/// for reproducible fixtures that don't depend on the clock.
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use datetime_default::DateTimeDefaultFixed;
+///
+/// assert_eq!(
+///     DateTimeDefaultFixed::<2022, 10, 10>::default(),
+///     Utc.with_ymd_and_hms(2022, 10, 10, 0, 0, 0).unwrap()
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DateTimeDefaultFixed<const YEAR: i32, const MONTH: u32, const DAY: u32>(DateTime<Utc>);
+
+impl<const YEAR: i32, const MONTH: u32, const DAY: u32> DateTimeDefaultFixed<YEAR, MONTH, DAY> {
+    /// Returns midnight UTC of the configured date.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the date does not exist, e.g. month 13 or February 30.
+    pub fn fixed() -> Self {
+        let date = NaiveDate::from_ymd_opt(YEAR, MONTH, DAY)
+            .unwrap_or_else(|| panic!("{YEAR:04}-{MONTH:02}-{DAY:02} is not a valid date"));
+        Self(date.and_time(NaiveTime::MIN).and_utc())
+    }
+
+    /// Returns `true` if the value is still the configured date, e.g. for `#[serde(skip_serializing_if)]`.
+    pub fn is_default(&self) -> bool {
+        self.0 == Self::fixed().0
+    }
+}
+
+impl<const YEAR: i32, const MONTH: u32, const DAY: u32> Default
+    for DateTimeDefaultFixed<YEAR, MONTH, DAY>
+{
+    fn default() -> Self {
+        Self::fixed()
+    }
+}
+
+impl<const YEAR: i32, const MONTH: u32, const DAY: u32> Deref
+    for DateTimeDefaultFixed<YEAR, MONTH, DAY>
+{
+    type Target = DateTime<Utc>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<const YEAR: i32, const MONTH: u32, const DAY: u32> From<DateTime<Utc>>
+    for DateTimeDefaultFixed<YEAR, MONTH, DAY>
+{
+    fn from(datetime: DateTime<Utc>) -> Self {
+        Self(datetime)
+    }
+}
+
+impl<const YEAR: i32, const MONTH: u32, const DAY: u32> From<DateTimeDefaultFixed<YEAR, MONTH, DAY>>
+    for DateTime<Utc>
+{
+    fn from(datetime: DateTimeDefaultFixed<YEAR, MONTH, DAY>) -> Self {
+        datetime.0
+    }
+}
+
+impl<const YEAR: i32, const MONTH: u32, const DAY: u32> core::cmp::PartialEq<DateTime<Utc>>
+    for DateTimeDefaultFixed<YEAR, MONTH, DAY>
+{
+    fn eq(&self, other: &DateTime<Utc>) -> bool {
+        self.0.eq(other)
+    }
+}
+
+impl<const YEAR: i32, const MONTH: u32, const DAY: u32>
+    core::cmp::PartialEq<DateTimeDefaultFixed<YEAR, MONTH, DAY>> for DateTime<Utc>
+{
+    fn eq(&self, other: &DateTimeDefaultFixed<YEAR, MONTH, DAY>) -> bool {
+        self.eq(&other.0)
+    }
+}
+
+impl<const YEAR: i32, const MONTH: u32, const DAY: u32> core::cmp::PartialOrd<DateTime<Utc>>
+    for DateTimeDefaultFixed<YEAR, MONTH, DAY>
+{
+    fn partial_cmp(&self, other: &DateTime<Utc>) -> Option<core::cmp::Ordering> {
+        self.0.partial_cmp(other)
+    }
+}
+
+impl<const YEAR: i32, const MONTH: u32, const DAY: u32>
+    core::cmp::PartialOrd<DateTimeDefaultFixed<YEAR, MONTH, DAY>> for DateTime<Utc>
+{
+    fn partial_cmp(
+        &self,
+        other: &DateTimeDefaultFixed<YEAR, MONTH, DAY>,
+    ) -> Option<core::cmp::Ordering> {
+        self.partial_cmp(&other.0)
+    }
+}
+
+impl<const YEAR: i32, const MONTH: u32, const DAY: u32> core::fmt::Display
+    for DateTimeDefaultFixed<YEAR, MONTH, DAY>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use chrono::{DateTime, Datelike, Duration, TimeZone, Timelike, Utc};
+
+    use crate::DateTimeDefaultFixed;
+
+    #[derive(Default)]
+    struct Test {
+        released_at: DateTimeDefaultFixed<2022, 10, 10>,
+    }
+
+    #[test]
+    fn default_derive_struct() {
+        let test = Test::default();
+
+        assert_eq!(
+            test.released_at,
+            Utc.with_ymd_and_hms(2022, 10, 10, 0, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn default_is_midnight() {
+        let datetime = DateTimeDefaultFixed::<2024, 2, 29>::default();
+
+        assert_eq!(
+            (datetime.year(), datetime.month(), datetime.day()),
+            (2024, 2, 29)
+        );
+        assert_eq!(
+            (
+                datetime.hour(),
+                datetime.minute(),
+                datetime.second(),
+                datetime.nanosecond()
+            ),
+            (0, 0, 0, 0)
+        );
+        assert_eq!(datetime, DateTimeDefaultFixed::<2024, 2, 29>::default());
+    }
+
+    #[test]
+    #[should_panic(expected = "2022-13-01 is not a valid date")]
+    fn invalid_month() {
+        DateTimeDefaultFixed::<2022, 13, 1>::default();
+    }
+
+    #[test]
+    #[should_panic(expected = "2023-02-29 is not a valid date")]
+    fn invalid_day() {
+        DateTimeDefaultFixed::<2023, 2, 29>::default();
+    }
+
+    #[test]
+    fn convert() {
+        let datetime = DateTimeDefaultFixed::<2022, 10, 10>::default();
+        let inner: DateTime<Utc> = datetime.into();
+
+        assert_eq!(DateTimeDefaultFixed::<2022, 10, 10>::from(inner), datetime);
+        assert!(datetime <= inner && inner <= datetime);
+    }
+
+    #[test]
+    fn is_default() {
+        let datetime = DateTimeDefaultFixed::<2022, 10, 10>::default();
+
+        assert!(datetime.is_default());
+        assert!(
+            !DateTimeDefaultFixed::<2022, 10, 10>::from(*datetime + Duration::seconds(1))
+                .is_default()
+        )
+    }
+}
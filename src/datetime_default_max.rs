@@ -0,0 +1,558 @@
+use core::ops::Deref;
+
+#[cfg(feature = "std")]
+use chrono::Local;
+use chrono::{DateTime, Duration, FixedOffset, TimeZone, Utc};
+
+/// # DateTime with maximum representable datetime as default.
+///
+/// ```
+/// use chrono::{DateTime, Utc};
+/// use datetime_default::DateTimeDefaultMax;
+///
+/// assert_eq!(DateTimeDefaultMax::<Utc>::default(), DateTime::<Utc>::MAX_UTC);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DateTimeDefaultMax<Tz, const OFFSET_HOURS: i32 = 0>(DateTime<Tz>)
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy;
+
+impl<const OFFSET_HOURS: i32> DateTimeDefaultMax<FixedOffset, OFFSET_HOURS> {
+    /// The default value, usable in `const` and `static` items.
+    pub const VALUE: Self = Self(DateTime::from_naive_utc_and_offset(
+        DateTimeDefaultMax::<Utc>::VALUE.0.naive_utc(),
+        match FixedOffset::east_opt(OFFSET_HOURS * 3600) {
+            Some(offset) if -12 <= OFFSET_HOURS && OFFSET_HOURS <= 14 => offset,
+            _ => panic!("OFFSET_HOURS must be within -12..=14"),
+        },
+    ));
+}
+
+impl<const OFFSET_HOURS: i32> Default for DateTimeDefaultMax<FixedOffset, OFFSET_HOURS> {
+    fn default() -> Self {
+        Self::VALUE
+    }
+}
+
+#[cfg(feature = "std")]
+impl Default for DateTimeDefaultMax<Local, 0> {
+    fn default() -> Self {
+        Self(DateTimeDefaultMax::<Utc>::default().with_timezone(&Local))
+    }
+}
+
+impl DateTimeDefaultMax<Utc, 0> {
+    /// The default value, usable in `const` and `static` items.
+    pub const VALUE: Self = Self(DateTime::<Utc>::MAX_UTC);
+}
+
+impl Default for DateTimeDefaultMax<Utc, 0> {
+    fn default() -> Self {
+        Self::VALUE
+    }
+}
+
+impl<Tz, const OFFSET_HOURS: i32> DateTimeDefaultMax<Tz, OFFSET_HOURS>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+    Self: Default,
+{
+    /// Returns `true` if the value is still the maximum datetime, e.g. for `#[serde(skip_serializing_if)]`.
+    pub fn is_default(&self) -> bool {
+        self.0 == Self::default().0
+    }
+}
+
+impl<Tz, const OFFSET_HOURS: i32> Deref for DateTimeDefaultMax<Tz, OFFSET_HOURS>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    type Target = DateTime<Tz>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<Tz, const OFFSET_HOURS: i32> From<DateTime<Tz>> for DateTimeDefaultMax<Tz, OFFSET_HOURS>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    fn from(datetime: DateTime<Tz>) -> Self {
+        Self(datetime)
+    }
+}
+
+impl<const OFFSET_HOURS: i32> core::str::FromStr for DateTimeDefaultMax<FixedOffset, OFFSET_HOURS> {
+    type Err = chrono::ParseError;
+
+    fn from_str(s: &str) -> chrono::ParseResult<DateTimeDefaultMax<FixedOffset, OFFSET_HOURS>> {
+        DateTime::<FixedOffset>::from_str(s).map(DateTimeDefaultMax::from)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<const OFFSET_HOURS: i32> core::str::FromStr for DateTimeDefaultMax<Local, OFFSET_HOURS> {
+    type Err = chrono::ParseError;
+
+    fn from_str(s: &str) -> chrono::ParseResult<DateTimeDefaultMax<Local, OFFSET_HOURS>> {
+        DateTime::<Local>::from_str(s).map(DateTimeDefaultMax::from)
+    }
+}
+
+impl<const OFFSET_HOURS: i32> core::str::FromStr for DateTimeDefaultMax<Utc, OFFSET_HOURS> {
+    type Err = chrono::ParseError;
+
+    fn from_str(s: &str) -> chrono::ParseResult<DateTimeDefaultMax<Utc, OFFSET_HOURS>> {
+        DateTime::<Utc>::from_str(s).map(DateTimeDefaultMax::from)
+    }
+}
+
+impl<Tz, const OFFSET_HOURS: i32> core::cmp::PartialEq<DateTime<Tz>>
+    for DateTimeDefaultMax<Tz, OFFSET_HOURS>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    fn eq(&self, other: &DateTime<Tz>) -> bool {
+        self.0.eq(other)
+    }
+}
+
+impl<Tz, const OFFSET_HOURS: i32> core::cmp::PartialEq<DateTimeDefaultMax<Tz, OFFSET_HOURS>>
+    for DateTime<Tz>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    fn eq(&self, other: &DateTimeDefaultMax<Tz, OFFSET_HOURS>) -> bool {
+        self.eq(&other.0)
+    }
+}
+
+impl<Tz, const OFFSET_HOURS: i32> core::cmp::PartialOrd<DateTime<Tz>>
+    for DateTimeDefaultMax<Tz, OFFSET_HOURS>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    fn partial_cmp(&self, other: &DateTime<Tz>) -> Option<core::cmp::Ordering> {
+        self.0.partial_cmp(other)
+    }
+}
+
+impl<Tz, const OFFSET_HOURS: i32> core::cmp::PartialOrd<DateTimeDefaultMax<Tz, OFFSET_HOURS>>
+    for DateTime<Tz>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    fn partial_cmp(
+        &self,
+        other: &DateTimeDefaultMax<Tz, OFFSET_HOURS>,
+    ) -> Option<core::cmp::Ordering> {
+        self.partial_cmp(&other.0)
+    }
+}
+
+impl<Tz, const OFFSET_HOURS: i32> core::ops::Add<Duration> for DateTimeDefaultMax<Tz, OFFSET_HOURS>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    type Output = DateTimeDefaultMax<Tz, OFFSET_HOURS>;
+
+    #[inline]
+    fn add(self, rhs: Duration) -> Self::Output {
+        DateTimeDefaultMax(self.0.add(rhs))
+    }
+}
+
+impl<Tz, const OFFSET_HOURS: i32> core::ops::Add<FixedOffset>
+    for DateTimeDefaultMax<Tz, OFFSET_HOURS>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    type Output = DateTimeDefaultMax<Tz, OFFSET_HOURS>;
+
+    #[inline]
+    fn add(self, rhs: FixedOffset) -> Self::Output {
+        DateTimeDefaultMax(self.0.add(rhs))
+    }
+}
+
+impl<Tz, const OFFSET_HOURS: i32> core::ops::AddAssign<Duration>
+    for DateTimeDefaultMax<Tz, OFFSET_HOURS>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    #[inline]
+    fn add_assign(&mut self, rhs: Duration) {
+        self.0.add_assign(rhs);
+    }
+}
+
+impl<Tz, const OFFSET_HOURS: i32> core::ops::Sub<DateTimeDefaultMax<Tz, OFFSET_HOURS>>
+    for DateTimeDefaultMax<Tz, OFFSET_HOURS>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    type Output = Duration;
+
+    #[inline]
+    fn sub(self, rhs: DateTimeDefaultMax<Tz, OFFSET_HOURS>) -> Duration {
+        self.0.sub(rhs.0)
+    }
+}
+
+impl<Tz, const OFFSET_HOURS: i32> core::ops::Sub<DateTime<Tz>>
+    for DateTimeDefaultMax<Tz, OFFSET_HOURS>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    type Output = Duration;
+
+    #[inline]
+    fn sub(self, rhs: DateTime<Tz>) -> Duration {
+        self.0.sub(rhs)
+    }
+}
+
+impl<Tz, const OFFSET_HOURS: i32> core::ops::Sub<Duration> for DateTimeDefaultMax<Tz, OFFSET_HOURS>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    type Output = DateTimeDefaultMax<Tz, OFFSET_HOURS>;
+
+    #[inline]
+    fn sub(self, rhs: Duration) -> Self::Output {
+        DateTimeDefaultMax(self.0.sub(rhs))
+    }
+}
+
+impl<Tz, const OFFSET_HOURS: i32> core::ops::Sub<FixedOffset>
+    for DateTimeDefaultMax<Tz, OFFSET_HOURS>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    type Output = DateTimeDefaultMax<Tz, OFFSET_HOURS>;
+
+    #[inline]
+    fn sub(self, rhs: FixedOffset) -> Self::Output {
+        DateTimeDefaultMax(self.0.sub(rhs))
+    }
+}
+
+impl<Tz, const OFFSET_HOURS: i32> core::ops::SubAssign<Duration>
+    for DateTimeDefaultMax<Tz, OFFSET_HOURS>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    #[inline]
+    fn sub_assign(&mut self, rhs: Duration) {
+        self.0.sub_assign(rhs);
+    }
+}
+
+impl<Tz, const OFFSET_HOURS: i32> chrono::DurationRound for DateTimeDefaultMax<Tz, OFFSET_HOURS>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    type Err = chrono::RoundingError;
+
+    fn duration_round(self, duration: Duration) -> Result<Self, Self::Err> {
+        self.0
+            .duration_round(duration)
+            .map(DateTimeDefaultMax::from)
+    }
+
+    fn duration_trunc(self, duration: Duration) -> Result<Self, Self::Err> {
+        self.0
+            .duration_trunc(duration)
+            .map(DateTimeDefaultMax::from)
+    }
+
+    fn duration_round_up(self, duration: Duration) -> Result<Self, Self::Err> {
+        self.0
+            .duration_round_up(duration)
+            .map(DateTimeDefaultMax::from)
+    }
+}
+
+impl<Tz, const OFFSET_HOURS: i32> chrono::Datelike for DateTimeDefaultMax<Tz, OFFSET_HOURS>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    #[inline]
+    fn year(&self) -> i32 {
+        self.0.year()
+    }
+    #[inline]
+    fn month(&self) -> u32 {
+        self.0.month()
+    }
+    #[inline]
+    fn month0(&self) -> u32 {
+        self.0.month0()
+    }
+    #[inline]
+    fn day(&self) -> u32 {
+        self.0.day()
+    }
+    #[inline]
+    fn day0(&self) -> u32 {
+        self.0.day0()
+    }
+    #[inline]
+    fn ordinal(&self) -> u32 {
+        self.0.ordinal()
+    }
+    #[inline]
+    fn ordinal0(&self) -> u32 {
+        self.0.ordinal0()
+    }
+    #[inline]
+    fn weekday(&self) -> chrono::Weekday {
+        self.0.weekday()
+    }
+    #[inline]
+    fn iso_week(&self) -> chrono::IsoWeek {
+        self.0.iso_week()
+    }
+
+    #[inline]
+    fn with_year(&self, year: i32) -> Option<DateTimeDefaultMax<Tz, OFFSET_HOURS>> {
+        self.0.with_year(year).map(DateTimeDefaultMax::from)
+    }
+
+    #[inline]
+    fn with_month(&self, month: u32) -> Option<DateTimeDefaultMax<Tz, OFFSET_HOURS>> {
+        self.0.with_month(month).map(DateTimeDefaultMax::from)
+    }
+
+    #[inline]
+    fn with_month0(&self, month0: u32) -> Option<DateTimeDefaultMax<Tz, OFFSET_HOURS>> {
+        self.0.with_month0(month0).map(DateTimeDefaultMax::from)
+    }
+
+    #[inline]
+    fn with_day(&self, day: u32) -> Option<DateTimeDefaultMax<Tz, OFFSET_HOURS>> {
+        self.0.with_day(day).map(DateTimeDefaultMax::from)
+    }
+
+    #[inline]
+    fn with_day0(&self, day0: u32) -> Option<DateTimeDefaultMax<Tz, OFFSET_HOURS>> {
+        self.0.with_day0(day0).map(DateTimeDefaultMax::from)
+    }
+
+    #[inline]
+    fn with_ordinal(&self, ordinal: u32) -> Option<DateTimeDefaultMax<Tz, OFFSET_HOURS>> {
+        self.0.with_ordinal(ordinal).map(DateTimeDefaultMax::from)
+    }
+
+    #[inline]
+    fn with_ordinal0(&self, ordinal0: u32) -> Option<DateTimeDefaultMax<Tz, OFFSET_HOURS>> {
+        self.0.with_ordinal0(ordinal0).map(DateTimeDefaultMax::from)
+    }
+}
+
+impl<Tz, const OFFSET_HOURS: i32> chrono::Timelike for DateTimeDefaultMax<Tz, OFFSET_HOURS>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    #[inline]
+    fn hour(&self) -> u32 {
+        self.0.hour()
+    }
+    #[inline]
+    fn minute(&self) -> u32 {
+        self.0.minute()
+    }
+    #[inline]
+    fn second(&self) -> u32 {
+        self.0.second()
+    }
+    #[inline]
+    fn nanosecond(&self) -> u32 {
+        self.0.nanosecond()
+    }
+
+    #[inline]
+    fn with_hour(&self, hour: u32) -> Option<DateTimeDefaultMax<Tz, OFFSET_HOURS>> {
+        self.0.with_hour(hour).map(DateTimeDefaultMax::from)
+    }
+
+    #[inline]
+    fn with_minute(&self, min: u32) -> Option<DateTimeDefaultMax<Tz, OFFSET_HOURS>> {
+        self.0.with_minute(min).map(DateTimeDefaultMax::from)
+    }
+
+    #[inline]
+    fn with_second(&self, sec: u32) -> Option<DateTimeDefaultMax<Tz, OFFSET_HOURS>> {
+        self.0.with_second(sec).map(DateTimeDefaultMax::from)
+    }
+
+    #[inline]
+    fn with_nanosecond(&self, nano: u32) -> Option<DateTimeDefaultMax<Tz, OFFSET_HOURS>> {
+        self.0.with_nanosecond(nano).map(DateTimeDefaultMax::from)
+    }
+}
+
+impl<Tz, const OFFSET_HOURS: i32> core::fmt::Display for DateTimeDefaultMax<Tz, OFFSET_HOURS>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy + core::fmt::Display,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    #[cfg(feature = "std")]
+    use chrono::Local;
+    use chrono::{DateTime, Duration, FixedOffset, Utc};
+
+    use crate::DateTimeDefaultMax;
+
+    #[derive(Default)]
+    struct Test {
+        updated_at: DateTimeDefaultMax<Utc>,
+    }
+
+    fn print_datetime(datetime: &DateTime<Utc>) {
+        println!("{datetime}")
+    }
+
+    #[test]
+    fn default_derive_struct() {
+        Test::default();
+    }
+
+    #[test]
+    fn use_default_derive_struct() {
+        let test = Test::default();
+
+        print_datetime(&test.updated_at)
+    }
+
+    #[test]
+    fn use_default_datetime() {
+        let datetime = DateTimeDefaultMax::<Utc>::default();
+
+        print_datetime(&datetime)
+    }
+
+    #[test]
+    fn default_is_stable() {
+        assert_eq!(
+            DateTimeDefaultMax::<Utc>::default(),
+            DateTimeDefaultMax::<Utc>::default()
+        );
+        assert_eq!(
+            DateTimeDefaultMax::<FixedOffset, 9>::default(),
+            DateTimeDefaultMax::<FixedOffset, 9>::default()
+        );
+    }
+
+    #[test]
+    fn fixed_offset() {
+        let datetime = DateTimeDefaultMax::<FixedOffset, 9>::default();
+
+        assert_eq!(*datetime, DateTime::<Utc>::MAX_UTC);
+        assert_eq!(datetime.offset(), &FixedOffset::east_opt(9 * 3600).unwrap());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn local() {
+        assert_eq!(
+            *DateTimeDefaultMax::<Local>::default(),
+            DateTime::<Utc>::MAX_UTC
+        );
+    }
+
+    #[test]
+    fn convert_from() {
+        let latest = DateTime::<Utc>::MAX_UTC;
+        let datetime = DateTimeDefaultMax::<Utc>::from(latest);
+
+        assert_eq!(datetime, latest)
+    }
+
+    #[test]
+    fn convert_into() {
+        let latest = DateTime::<Utc>::MAX_UTC;
+        let datetime: DateTimeDefaultMax<Utc> = latest.into();
+
+        assert_eq!(datetime, latest)
+    }
+
+    #[test]
+    fn equation1() {
+        let latest = DateTime::<Utc>::MAX_UTC;
+        let datetime: DateTimeDefaultMax<Utc> = latest.into();
+        assert!(datetime == latest)
+    }
+
+    #[test]
+    fn equation2() {
+        let latest = DateTime::<Utc>::MAX_UTC;
+        let datetime: DateTimeDefaultMax<Utc> = latest.into();
+        assert!(latest == datetime)
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn comparing1() {
+        assert!(DateTimeDefaultMax::<Utc>::default() >= Utc::now())
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn comparing2() {
+        assert!(DateTimeDefaultMax::<Local>::default() >= Local::now())
+    }
+
+    const UTC_VALUE: DateTimeDefaultMax<Utc> = DateTimeDefaultMax::<Utc>::VALUE;
+    static TOKYO_VALUE: DateTimeDefaultMax<FixedOffset, 9> =
+        DateTimeDefaultMax::<FixedOffset, 9>::VALUE;
+
+    #[test]
+    fn const_value() {
+        assert_eq!(UTC_VALUE, DateTimeDefaultMax::<Utc>::default());
+        assert_eq!(TOKYO_VALUE, DateTimeDefaultMax::<FixedOffset, 9>::default());
+        assert_eq!(
+            TOKYO_VALUE.offset(),
+            &FixedOffset::east_opt(9 * 3600).unwrap()
+        )
+    }
+
+    #[test]
+    fn is_default() {
+        assert!(DateTimeDefaultMax::<Utc>::default().is_default());
+        assert!(DateTimeDefaultMax::<FixedOffset, 9>::default().is_default());
+        #[cfg(feature = "std")]
+        assert!(DateTimeDefaultMax::<Local>::default().is_default());
+        assert!(!DateTimeDefaultMax::<Utc>::from(
+            *DateTimeDefaultMax::<Utc>::default() - Duration::seconds(1)
+        )
+        .is_default())
+    }
+}
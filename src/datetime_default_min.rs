@@ -0,0 +1,558 @@
+use core::ops::Deref;
+
+#[cfg(feature = "std")]
+use chrono::Local;
+use chrono::{DateTime, Duration, FixedOffset, TimeZone, Utc};
+
+/// # DateTime with minimum representable datetime as default.
+///
+/// ```
+/// use chrono::{DateTime, Utc};
+/// use datetime_default::DateTimeDefaultMin;
+///
+/// assert_eq!(DateTimeDefaultMin::<Utc>::default(), DateTime::<Utc>::MIN_UTC);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DateTimeDefaultMin<Tz, const OFFSET_HOURS: i32 = 0>(DateTime<Tz>)
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy;
+
+impl<const OFFSET_HOURS: i32> DateTimeDefaultMin<FixedOffset, OFFSET_HOURS> {
+    /// The default value, usable in `const` and `static` items.
+    pub const VALUE: Self = Self(DateTime::from_naive_utc_and_offset(
+        DateTimeDefaultMin::<Utc>::VALUE.0.naive_utc(),
+        match FixedOffset::east_opt(OFFSET_HOURS * 3600) {
+            Some(offset) if -12 <= OFFSET_HOURS && OFFSET_HOURS <= 14 => offset,
+            _ => panic!("OFFSET_HOURS must be within -12..=14"),
+        },
+    ));
+}
+
+impl<const OFFSET_HOURS: i32> Default for DateTimeDefaultMin<FixedOffset, OFFSET_HOURS> {
+    fn default() -> Self {
+        Self::VALUE
+    }
+}
+
+#[cfg(feature = "std")]
+impl Default for DateTimeDefaultMin<Local, 0> {
+    fn default() -> Self {
+        Self(DateTimeDefaultMin::<Utc>::default().with_timezone(&Local))
+    }
+}
+
+impl DateTimeDefaultMin<Utc, 0> {
+    /// The default value, usable in `const` and `static` items.
+    pub const VALUE: Self = Self(DateTime::<Utc>::MIN_UTC);
+}
+
+impl Default for DateTimeDefaultMin<Utc, 0> {
+    fn default() -> Self {
+        Self::VALUE
+    }
+}
+
+impl<Tz, const OFFSET_HOURS: i32> DateTimeDefaultMin<Tz, OFFSET_HOURS>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+    Self: Default,
+{
+    /// Returns `true` if the value is still the minimum datetime, e.g. for `#[serde(skip_serializing_if)]`.
+    pub fn is_default(&self) -> bool {
+        self.0 == Self::default().0
+    }
+}
+
+impl<Tz, const OFFSET_HOURS: i32> Deref for DateTimeDefaultMin<Tz, OFFSET_HOURS>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    type Target = DateTime<Tz>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<Tz, const OFFSET_HOURS: i32> From<DateTime<Tz>> for DateTimeDefaultMin<Tz, OFFSET_HOURS>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    fn from(datetime: DateTime<Tz>) -> Self {
+        Self(datetime)
+    }
+}
+
+impl<const OFFSET_HOURS: i32> core::str::FromStr for DateTimeDefaultMin<FixedOffset, OFFSET_HOURS> {
+    type Err = chrono::ParseError;
+
+    fn from_str(s: &str) -> chrono::ParseResult<DateTimeDefaultMin<FixedOffset, OFFSET_HOURS>> {
+        DateTime::<FixedOffset>::from_str(s).map(DateTimeDefaultMin::from)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<const OFFSET_HOURS: i32> core::str::FromStr for DateTimeDefaultMin<Local, OFFSET_HOURS> {
+    type Err = chrono::ParseError;
+
+    fn from_str(s: &str) -> chrono::ParseResult<DateTimeDefaultMin<Local, OFFSET_HOURS>> {
+        DateTime::<Local>::from_str(s).map(DateTimeDefaultMin::from)
+    }
+}
+
+impl<const OFFSET_HOURS: i32> core::str::FromStr for DateTimeDefaultMin<Utc, OFFSET_HOURS> {
+    type Err = chrono::ParseError;
+
+    fn from_str(s: &str) -> chrono::ParseResult<DateTimeDefaultMin<Utc, OFFSET_HOURS>> {
+        DateTime::<Utc>::from_str(s).map(DateTimeDefaultMin::from)
+    }
+}
+
+impl<Tz, const OFFSET_HOURS: i32> core::cmp::PartialEq<DateTime<Tz>>
+    for DateTimeDefaultMin<Tz, OFFSET_HOURS>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    fn eq(&self, other: &DateTime<Tz>) -> bool {
+        self.0.eq(other)
+    }
+}
+
+impl<Tz, const OFFSET_HOURS: i32> core::cmp::PartialEq<DateTimeDefaultMin<Tz, OFFSET_HOURS>>
+    for DateTime<Tz>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    fn eq(&self, other: &DateTimeDefaultMin<Tz, OFFSET_HOURS>) -> bool {
+        self.eq(&other.0)
+    }
+}
+
+impl<Tz, const OFFSET_HOURS: i32> core::cmp::PartialOrd<DateTime<Tz>>
+    for DateTimeDefaultMin<Tz, OFFSET_HOURS>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    fn partial_cmp(&self, other: &DateTime<Tz>) -> Option<core::cmp::Ordering> {
+        self.0.partial_cmp(other)
+    }
+}
+
+impl<Tz, const OFFSET_HOURS: i32> core::cmp::PartialOrd<DateTimeDefaultMin<Tz, OFFSET_HOURS>>
+    for DateTime<Tz>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    fn partial_cmp(
+        &self,
+        other: &DateTimeDefaultMin<Tz, OFFSET_HOURS>,
+    ) -> Option<core::cmp::Ordering> {
+        self.partial_cmp(&other.0)
+    }
+}
+
+impl<Tz, const OFFSET_HOURS: i32> core::ops::Add<Duration> for DateTimeDefaultMin<Tz, OFFSET_HOURS>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    type Output = DateTimeDefaultMin<Tz, OFFSET_HOURS>;
+
+    #[inline]
+    fn add(self, rhs: Duration) -> Self::Output {
+        DateTimeDefaultMin(self.0.add(rhs))
+    }
+}
+
+impl<Tz, const OFFSET_HOURS: i32> core::ops::Add<FixedOffset>
+    for DateTimeDefaultMin<Tz, OFFSET_HOURS>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    type Output = DateTimeDefaultMin<Tz, OFFSET_HOURS>;
+
+    #[inline]
+    fn add(self, rhs: FixedOffset) -> Self::Output {
+        DateTimeDefaultMin(self.0.add(rhs))
+    }
+}
+
+impl<Tz, const OFFSET_HOURS: i32> core::ops::AddAssign<Duration>
+    for DateTimeDefaultMin<Tz, OFFSET_HOURS>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    #[inline]
+    fn add_assign(&mut self, rhs: Duration) {
+        self.0.add_assign(rhs);
+    }
+}
+
+impl<Tz, const OFFSET_HOURS: i32> core::ops::Sub<DateTimeDefaultMin<Tz, OFFSET_HOURS>>
+    for DateTimeDefaultMin<Tz, OFFSET_HOURS>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    type Output = Duration;
+
+    #[inline]
+    fn sub(self, rhs: DateTimeDefaultMin<Tz, OFFSET_HOURS>) -> Duration {
+        self.0.sub(rhs.0)
+    }
+}
+
+impl<Tz, const OFFSET_HOURS: i32> core::ops::Sub<DateTime<Tz>>
+    for DateTimeDefaultMin<Tz, OFFSET_HOURS>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    type Output = Duration;
+
+    #[inline]
+    fn sub(self, rhs: DateTime<Tz>) -> Duration {
+        self.0.sub(rhs)
+    }
+}
+
+impl<Tz, const OFFSET_HOURS: i32> core::ops::Sub<Duration> for DateTimeDefaultMin<Tz, OFFSET_HOURS>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    type Output = DateTimeDefaultMin<Tz, OFFSET_HOURS>;
+
+    #[inline]
+    fn sub(self, rhs: Duration) -> Self::Output {
+        DateTimeDefaultMin(self.0.sub(rhs))
+    }
+}
+
+impl<Tz, const OFFSET_HOURS: i32> core::ops::Sub<FixedOffset>
+    for DateTimeDefaultMin<Tz, OFFSET_HOURS>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    type Output = DateTimeDefaultMin<Tz, OFFSET_HOURS>;
+
+    #[inline]
+    fn sub(self, rhs: FixedOffset) -> Self::Output {
+        DateTimeDefaultMin(self.0.sub(rhs))
+    }
+}
+
+impl<Tz, const OFFSET_HOURS: i32> core::ops::SubAssign<Duration>
+    for DateTimeDefaultMin<Tz, OFFSET_HOURS>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    #[inline]
+    fn sub_assign(&mut self, rhs: Duration) {
+        self.0.sub_assign(rhs);
+    }
+}
+
+impl<Tz, const OFFSET_HOURS: i32> chrono::DurationRound for DateTimeDefaultMin<Tz, OFFSET_HOURS>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    type Err = chrono::RoundingError;
+
+    fn duration_round(self, duration: Duration) -> Result<Self, Self::Err> {
+        self.0
+            .duration_round(duration)
+            .map(DateTimeDefaultMin::from)
+    }
+
+    fn duration_trunc(self, duration: Duration) -> Result<Self, Self::Err> {
+        self.0
+            .duration_trunc(duration)
+            .map(DateTimeDefaultMin::from)
+    }
+
+    fn duration_round_up(self, duration: Duration) -> Result<Self, Self::Err> {
+        self.0
+            .duration_round_up(duration)
+            .map(DateTimeDefaultMin::from)
+    }
+}
+
+impl<Tz, const OFFSET_HOURS: i32> chrono::Datelike for DateTimeDefaultMin<Tz, OFFSET_HOURS>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    #[inline]
+    fn year(&self) -> i32 {
+        self.0.year()
+    }
+    #[inline]
+    fn month(&self) -> u32 {
+        self.0.month()
+    }
+    #[inline]
+    fn month0(&self) -> u32 {
+        self.0.month0()
+    }
+    #[inline]
+    fn day(&self) -> u32 {
+        self.0.day()
+    }
+    #[inline]
+    fn day0(&self) -> u32 {
+        self.0.day0()
+    }
+    #[inline]
+    fn ordinal(&self) -> u32 {
+        self.0.ordinal()
+    }
+    #[inline]
+    fn ordinal0(&self) -> u32 {
+        self.0.ordinal0()
+    }
+    #[inline]
+    fn weekday(&self) -> chrono::Weekday {
+        self.0.weekday()
+    }
+    #[inline]
+    fn iso_week(&self) -> chrono::IsoWeek {
+        self.0.iso_week()
+    }
+
+    #[inline]
+    fn with_year(&self, year: i32) -> Option<DateTimeDefaultMin<Tz, OFFSET_HOURS>> {
+        self.0.with_year(year).map(DateTimeDefaultMin::from)
+    }
+
+    #[inline]
+    fn with_month(&self, month: u32) -> Option<DateTimeDefaultMin<Tz, OFFSET_HOURS>> {
+        self.0.with_month(month).map(DateTimeDefaultMin::from)
+    }
+
+    #[inline]
+    fn with_month0(&self, month0: u32) -> Option<DateTimeDefaultMin<Tz, OFFSET_HOURS>> {
+        self.0.with_month0(month0).map(DateTimeDefaultMin::from)
+    }
+
+    #[inline]
+    fn with_day(&self, day: u32) -> Option<DateTimeDefaultMin<Tz, OFFSET_HOURS>> {
+        self.0.with_day(day).map(DateTimeDefaultMin::from)
+    }
+
+    #[inline]
+    fn with_day0(&self, day0: u32) -> Option<DateTimeDefaultMin<Tz, OFFSET_HOURS>> {
+        self.0.with_day0(day0).map(DateTimeDefaultMin::from)
+    }
+
+    #[inline]
+    fn with_ordinal(&self, ordinal: u32) -> Option<DateTimeDefaultMin<Tz, OFFSET_HOURS>> {
+        self.0.with_ordinal(ordinal).map(DateTimeDefaultMin::from)
+    }
+
+    #[inline]
+    fn with_ordinal0(&self, ordinal0: u32) -> Option<DateTimeDefaultMin<Tz, OFFSET_HOURS>> {
+        self.0.with_ordinal0(ordinal0).map(DateTimeDefaultMin::from)
+    }
+}
+
+impl<Tz, const OFFSET_HOURS: i32> chrono::Timelike for DateTimeDefaultMin<Tz, OFFSET_HOURS>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    #[inline]
+    fn hour(&self) -> u32 {
+        self.0.hour()
+    }
+    #[inline]
+    fn minute(&self) -> u32 {
+        self.0.minute()
+    }
+    #[inline]
+    fn second(&self) -> u32 {
+        self.0.second()
+    }
+    #[inline]
+    fn nanosecond(&self) -> u32 {
+        self.0.nanosecond()
+    }
+
+    #[inline]
+    fn with_hour(&self, hour: u32) -> Option<DateTimeDefaultMin<Tz, OFFSET_HOURS>> {
+        self.0.with_hour(hour).map(DateTimeDefaultMin::from)
+    }
+
+    #[inline]
+    fn with_minute(&self, min: u32) -> Option<DateTimeDefaultMin<Tz, OFFSET_HOURS>> {
+        self.0.with_minute(min).map(DateTimeDefaultMin::from)
+    }
+
+    #[inline]
+    fn with_second(&self, sec: u32) -> Option<DateTimeDefaultMin<Tz, OFFSET_HOURS>> {
+        self.0.with_second(sec).map(DateTimeDefaultMin::from)
+    }
+
+    #[inline]
+    fn with_nanosecond(&self, nano: u32) -> Option<DateTimeDefaultMin<Tz, OFFSET_HOURS>> {
+        self.0.with_nanosecond(nano).map(DateTimeDefaultMin::from)
+    }
+}
+
+impl<Tz, const OFFSET_HOURS: i32> core::fmt::Display for DateTimeDefaultMin<Tz, OFFSET_HOURS>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy + core::fmt::Display,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    #[cfg(feature = "std")]
+    use chrono::Local;
+    use chrono::{DateTime, Duration, FixedOffset, Utc};
+
+    use crate::DateTimeDefaultMin;
+
+    #[derive(Default)]
+    struct Test {
+        updated_at: DateTimeDefaultMin<Utc>,
+    }
+
+    fn print_datetime(datetime: &DateTime<Utc>) {
+        println!("{datetime}")
+    }
+
+    #[test]
+    fn default_derive_struct() {
+        Test::default();
+    }
+
+    #[test]
+    fn use_default_derive_struct() {
+        let test = Test::default();
+
+        print_datetime(&test.updated_at)
+    }
+
+    #[test]
+    fn use_default_datetime() {
+        let datetime = DateTimeDefaultMin::<Utc>::default();
+
+        print_datetime(&datetime)
+    }
+
+    #[test]
+    fn default_is_stable() {
+        assert_eq!(
+            DateTimeDefaultMin::<Utc>::default(),
+            DateTimeDefaultMin::<Utc>::default()
+        );
+        assert_eq!(
+            DateTimeDefaultMin::<FixedOffset, 9>::default(),
+            DateTimeDefaultMin::<FixedOffset, 9>::default()
+        );
+    }
+
+    #[test]
+    fn fixed_offset() {
+        let datetime = DateTimeDefaultMin::<FixedOffset, 9>::default();
+
+        assert_eq!(*datetime, DateTime::<Utc>::MIN_UTC);
+        assert_eq!(datetime.offset(), &FixedOffset::east_opt(9 * 3600).unwrap());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn local() {
+        assert_eq!(
+            *DateTimeDefaultMin::<Local>::default(),
+            DateTime::<Utc>::MIN_UTC
+        );
+    }
+
+    #[test]
+    fn convert_from() {
+        let earliest = DateTime::<Utc>::MIN_UTC;
+        let datetime = DateTimeDefaultMin::<Utc>::from(earliest);
+
+        assert_eq!(datetime, earliest)
+    }
+
+    #[test]
+    fn convert_into() {
+        let earliest = DateTime::<Utc>::MIN_UTC;
+        let datetime: DateTimeDefaultMin<Utc> = earliest.into();
+
+        assert_eq!(datetime, earliest)
+    }
+
+    #[test]
+    fn equation1() {
+        let earliest = DateTime::<Utc>::MIN_UTC;
+        let datetime: DateTimeDefaultMin<Utc> = earliest.into();
+        assert!(datetime == earliest)
+    }
+
+    #[test]
+    fn equation2() {
+        let earliest = DateTime::<Utc>::MIN_UTC;
+        let datetime: DateTimeDefaultMin<Utc> = earliest.into();
+        assert!(earliest == datetime)
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn comparing1() {
+        assert!(DateTimeDefaultMin::<Utc>::default() <= Utc::now())
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn comparing2() {
+        assert!(DateTimeDefaultMin::<Local>::default() <= Local::now())
+    }
+
+    const UTC_VALUE: DateTimeDefaultMin<Utc> = DateTimeDefaultMin::<Utc>::VALUE;
+    static TOKYO_VALUE: DateTimeDefaultMin<FixedOffset, 9> =
+        DateTimeDefaultMin::<FixedOffset, 9>::VALUE;
+
+    #[test]
+    fn const_value() {
+        assert_eq!(UTC_VALUE, DateTimeDefaultMin::<Utc>::default());
+        assert_eq!(TOKYO_VALUE, DateTimeDefaultMin::<FixedOffset, 9>::default());
+        assert_eq!(
+            TOKYO_VALUE.offset(),
+            &FixedOffset::east_opt(9 * 3600).unwrap()
+        )
+    }
+
+    #[test]
+    fn is_default() {
+        assert!(DateTimeDefaultMin::<Utc>::default().is_default());
+        assert!(DateTimeDefaultMin::<FixedOffset, 9>::default().is_default());
+        #[cfg(feature = "std")]
+        assert!(DateTimeDefaultMin::<Local>::default().is_default());
+        assert!(!DateTimeDefaultMin::<Utc>::from(
+            *DateTimeDefaultMin::<Utc>::default() + Duration::seconds(1)
+        )
+        .is_default())
+    }
+}
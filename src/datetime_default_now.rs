@@ -1,217 +1,1296 @@
-use std::ops::Deref;
-
-use chrono::{DateTime, Duration, FixedOffset, Local, TimeZone, Utc};
-
-#[cfg(test)]
-const NOW: &str = "2022/10/10 23:40:11.695164300";
+use alloc::{
+    format,
+    string::{String, ToString},
+};
+use core::ops::{Deref, DerefMut};
+#[cfg(feature = "std")]
+use std::time::SystemTime;
+
+#[cfg(feature = "std")]
+use chrono::Local;
+use chrono::{
+    format::{self, Parsed, StrftimeItems},
+    DateTime, Days, Duration, FixedOffset, Months, NaiveDate, NaiveDateTime, NaiveTime, Offset,
+    SubsecRound, TimeZone, Timelike, Utc,
+};
+
+use crate::CurrentTime;
+#[cfg(feature = "std")]
+use crate::{clock, DefaultError};
+#[cfg(all(feature = "std", not(test)))]
+use crate::{clock::SystemClock, Clock};
 
 /// # DateTime with current time as default.
 ///
 /// ```should_panic
-/// use chrono::{Local, TimeZone};
+/// # #[cfg(feature = "std")] {
+/// use chrono::{Local, NaiveDateTime};
 /// use datetime_default::DateTimeDefaultNow;
 ///
 /// assert_eq!(
 ///     DateTimeDefaultNow::<Local>::default(),
-///     Local.datetime_from_str("2022/10/10 23:40:11.695164300", "%Y/%m/%d %H:%M:%S%.9f").unwrap()
+///     NaiveDateTime::parse_from_str("2022/10/10 23:40:11.695164300", "%Y/%m/%d %H:%M:%S%.9f")
+///         .unwrap()
+///         .and_local_timezone(Local)
+///         .unwrap()
+/// );
+/// # }
+/// # #[cfg(not(feature = "std"))]
+/// # panic!("needs the `std` feature");
+/// ```
+///
+/// With `FixedOffset`, `OFFSET_HOURS` and `OFFSET_MINUTES` are added together,
+/// so zones such as India (+05:30) can be expressed.
+///
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use chrono::FixedOffset;
+/// use datetime_default::DateTimeDefaultNow;
+///
+/// assert_eq!(
+///     DateTimeDefaultNow::<FixedOffset, 5, 30>::default().offset(),
+///     &FixedOffset::east_opt(5 * 3600 + 30 * 60).unwrap()
 /// );
+/// # }
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct DateTimeDefaultNow<Tz, const OFFSET_HOURS: i32 = 0>(DateTime<Tz>)
+#[derive(Clone, Copy)]
+#[cfg_attr(
+    feature = "diesel",
+    derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow),
+    diesel(sql_type = diesel::sql_types::Timestamptz)
+)]
+pub struct DateTimeDefaultNow<Tz, const OFFSET_HOURS: i32 = 0, const OFFSET_MINUTES: i32 = 0>(
+    DateTime<Tz>,
+)
 where
     Tz: TimeZone,
     <Tz as TimeZone>::Offset: Copy;
 
-impl<const OFFSET_HOURS: i32> Default for DateTimeDefaultNow<FixedOffset, OFFSET_HOURS> {
-    fn default() -> Self {
-        Self(
-            DateTimeDefaultNow::<Utc>::default()
-                .with_timezone(&FixedOffset::east(OFFSET_HOURS * 3600)),
+impl<Tz, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32>
+    DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    /// Returns the `OFFSET_HOURS` const parameter.
+    pub const fn offset_hours() -> i32 {
+        OFFSET_HOURS
+    }
+
+    /// Returns the `OFFSET_MINUTES` const parameter.
+    pub const fn offset_minutes() -> i32 {
+        OFFSET_MINUTES
+    }
+
+    /// Unwraps the inner `DateTime`.
+    pub fn into_inner(self) -> DateTime<Tz> {
+        self.0
+    }
+
+    /// Borrows the inner `DateTime`.
+    pub fn as_inner(&self) -> &DateTime<Tz> {
+        &self.0
+    }
+
+    /// Transforms the inner `DateTime`, staying inside the wrapper.
+    pub fn map<F>(self, f: F) -> Self
+    where
+        F: FnOnce(DateTime<Tz>) -> DateTime<Tz>,
+    {
+        Self(f(self.0))
+    }
+
+    /// Converts the instant into another timezone, staying inside the wrapper.
+    pub fn to_timezone<Tz2>(&self, tz: &Tz2) -> DateTimeDefaultNow<Tz2>
+    where
+        Tz2: TimeZone,
+        <Tz2 as TimeZone>::Offset: Copy,
+    {
+        DateTimeDefaultNow(self.0.with_timezone(tz))
+    }
+
+    /// Converts the instant into UTC.
+    pub fn to_utc(&self) -> DateTimeDefaultNow<Utc> {
+        DateTimeDefaultNow(self.0.with_timezone(&Utc))
+    }
+
+    /// Converts the instant into the machine's local timezone, e.g. for display.
+    #[cfg(feature = "std")]
+    pub fn to_local(&self) -> DateTimeDefaultNow<Local> {
+        DateTimeDefaultNow(self.0.with_timezone(&Local))
+    }
+
+    /// Converts the instant into the `FixedOffset` wrapper at `H` hours,
+    /// e.g. to persist `Local` values the same way on every machine.
+    pub fn to_fixed<const H: i32>(&self) -> DateTimeDefaultNow<FixedOffset, H> {
+        DateTimeDefaultNow::from(self.0.with_timezone(&Utc))
+    }
+
+    /// Formats the datetime with a strftime-style format string, e.g. `"%Y-%m-%d"`.
+    ///
+    /// Panics like chrono's `format(...).to_string()` if the format string is invalid.
+    pub fn format(&self, fmt: &str) -> String
+    where
+        <Tz as TimeZone>::Offset: core::fmt::Display,
+    {
+        self.0.format(fmt).to_string()
+    }
+
+    /// Formats as RFC 3339 with `secform` fractional digits, writing a zero offset as `Z` if `use_z`.
+    ///
+    /// Same as chrono's `to_rfc3339_opts`, e.g. `SecondsFormat::Millis, true` for `2022-10-10T23:40:11.695Z`.
+    pub fn to_rfc3339_opts(&self, secform: chrono::SecondsFormat, use_z: bool) -> String
+    where
+        <Tz as TimeZone>::Offset: core::fmt::Display,
+    {
+        self.0.to_rfc3339_opts(secform, use_z)
+    }
+
+    /// Renders a colon-free basic timestamp like `20221010T234011Z`, safe in filenames.
+    ///
+    /// A zero offset is written as `Z`, anything else as `+hhmm`, so names within one zone sort chronologically.
+    pub fn to_filename_string(&self) -> String {
+        let offset = self.0.offset().fix();
+        let datetime = self.0.with_timezone(&offset);
+        if offset.local_minus_utc() == 0 {
+            datetime.format("%Y%m%dT%H%M%SZ").to_string()
+        } else {
+            datetime.format("%Y%m%dT%H%M%S%z").to_string()
+        }
+    }
+
+    /// Returns the duration from `earlier` to `self`, negative if `earlier` is later.
+    pub fn elapsed_since(&self, earlier: &Self) -> Duration {
+        self.0 - earlier.0
+    }
+
+    /// Returns `self - other`, like chrono's method of the same name.
+    ///
+    /// The `wrapper - wrapper` operator is sugar for this method.
+    /// Compare with a bare `DateTime` through `Deref`, e.g. `(*a).signed_duration_since(b)`.
+    pub fn signed_duration_since(&self, other: &Self) -> Duration {
+        self.0.signed_duration_since(other.0)
+    }
+
+    /// Returns the duration from `self` to the current time, honoring the test clock.
+    ///
+    /// Positive if `self` is in the past, e.g. the age of a `created_at` timestamp.
+    #[cfg(feature = "std")]
+    pub fn until_now(&self) -> Duration {
+        DateTimeDefaultNow::<Utc>::now()
+            .0
+            .signed_duration_since(self.0)
+    }
+
+    /// Returns the time elapsed since `self` as a `std::time::Duration`, honoring the test clock.
+    ///
+    /// Fails if `self` is in the future, as `std::time::Duration` cannot be negative.
+    #[cfg(feature = "std")]
+    pub fn elapsed_std(&self) -> Result<std::time::Duration, chrono::OutOfRangeError> {
+        self.until_now().to_std()
+    }
+
+    /// Returns the duration from the current time to `self`, honoring the test clock.
+    ///
+    /// Positive if `self` is in the future, e.g. the time left before an `expires_at` timestamp.
+    #[cfg(feature = "std")]
+    pub fn since_now(&self) -> Duration {
+        self.0
+            .signed_duration_since(DateTimeDefaultNow::<Utc>::now().0)
+    }
+
+    /// Describes the datetime relative to the current time, e.g. `"3 hours ago"` or `"in 2 days"`.
+    ///
+    /// The largest fitting unit out of seconds, minutes, hours and days is used, rounded down.
+    #[cfg(feature = "std")]
+    pub fn humanize(&self) -> String {
+        const UNITS: [(u64, &str, &str); 4] = [
+            (86400, "a day", "days"),
+            (3600, "an hour", "hours"),
+            (60, "a minute", "minutes"),
+            (1, "a second", "seconds"),
+        ];
+
+        let elapsed = self.until_now().num_seconds();
+        let seconds = elapsed.unsigned_abs();
+        let Some(&(size, one, many)) = UNITS.iter().find(|(size, ..)| seconds >= *size) else {
+            return String::from("just now");
+        };
+        let amount = match seconds / size {
+            1 => String::from(one),
+            count => format!("{count} {many}"),
+        };
+
+        if elapsed > 0 {
+            format!("{amount} ago")
+        } else {
+            format!("in {amount}")
+        }
+    }
+
+    /// Sums the gaps between consecutive datetimes, which equals the span from first to last.
+    ///
+    /// Returns `Duration::zero()` for fewer than two datetimes.
+    pub fn total_elapsed(datetimes: &[Self]) -> Duration {
+        datetimes
+            .windows(2)
+            .map(|pair| pair[1].elapsed_since(&pair[0]))
+            .fold(Duration::zero(), |total, gap| total + gap)
+    }
+
+    /// Returns `true` if both datetimes fall on the same calendar date.
+    ///
+    /// Each date is read in its own offset, so for `FixedOffset` values
+    /// two instants at different offsets may compare differently than in UTC.
+    pub fn same_date(&self, other: &Self) -> bool {
+        self.0.date_naive() == other.0.date_naive()
+    }
+
+    /// Returns the ISO week of the date, in the datetime's own offset.
+    pub fn iso_week(&self) -> chrono::IsoWeek {
+        chrono::Datelike::iso_week(&self.0)
+    }
+
+    /// Returns the day of week of the date, in the datetime's own offset.
+    pub fn weekday(&self) -> chrono::Weekday {
+        chrono::Datelike::weekday(&self.0)
+    }
+
+    /// Returns the number of milliseconds since the UNIX epoch.
+    pub fn timestamp_millis(&self) -> i64 {
+        self.0.timestamp_millis()
+    }
+
+    /// Returns the number of microseconds since the UNIX epoch.
+    pub fn timestamp_micros(&self) -> i64 {
+        self.0.timestamp_micros()
+    }
+
+    /// Returns the number of nanoseconds since the UNIX epoch.
+    ///
+    /// Returns `None` outside roughly 1677-09-21 to 2262-04-11, which does not fit in an `i64`.
+    pub fn timestamp_nanos_opt(&self) -> Option<i64> {
+        self.0.timestamp_nanos_opt()
+    }
+
+    /// Yields `self`, `self + step`, `self + 2 * step`, ..., stopping before the first overflow.
+    pub fn step_iter(self, step: Duration) -> impl Iterator<Item = Self> {
+        core::iter::successors(Some(self), move |datetime| {
+            datetime.0.checked_add_signed(step).map(Self)
+        })
+    }
+
+    /// Yields successive business days from the wrapper's own date, itself included if it is one,
+    /// keeping the time of day and offset. Saturday and Sunday are always the weekend.
+    ///
+    /// Stops before the first overflow, or for `Local` where the time does not exist on a day.
+    pub fn business_days(self) -> impl Iterator<Item = Self> {
+        core::iter::successors(Some(self), |datetime| datetime.checked_add_days(1)).filter(
+            |datetime| {
+                !matches!(
+                    datetime.weekday(),
+                    chrono::Weekday::Sat | chrono::Weekday::Sun
+                )
+            },
         )
     }
-}
 
-impl Default for DateTimeDefaultNow<Local, 0> {
-    #[cfg(not(test))]
-    fn default() -> Self {
-        Self(Local::now())
+    /// Returns `true` if both instants are at most `tolerance` apart.
+    ///
+    /// Useful for asserting that two "roughly now" defaults match without pinning the clock.
+    pub fn approx_eq(&self, other: &Self, tolerance: Duration) -> bool {
+        self.elapsed_since(other).abs() <= tolerance.abs()
     }
 
-    #[cfg(test)]
-    fn default() -> Self {
-        Self(
-            Local
-                .datetime_from_str("2022/10/10 23:40:11.695164300", "%Y/%m/%d %H:%M:%S%.9f")
-                .unwrap(),
+    /// Returns `true` if both instants fall within the same whole UTC second,
+    /// i.e. they are equal once truncated to seconds.
+    ///
+    /// Unlike `==`, this survives the nanosecond drift between a default and a separately captured "now".
+    pub fn eq_to_second(&self, other: &DateTime<Tz>) -> bool {
+        self.0.timestamp() == other.timestamp()
+    }
+
+    /// Returns the datetime with the sub-second part zeroed.
+    pub fn truncated_to_seconds(&self) -> Self {
+        Self(self.0.with_nanosecond(0).unwrap_or(self.0))
+    }
+
+    /// Returns the datetime with seconds and finer fields zeroed.
+    pub fn truncated_to_minutes(&self) -> Self {
+        let datetime = self.truncated_to_seconds().0;
+        Self(datetime.with_second(0).unwrap_or(datetime))
+    }
+
+    /// Returns the datetime with minutes and finer fields zeroed.
+    pub fn truncated_to_hours(&self) -> Self {
+        let datetime = self.truncated_to_minutes().0;
+        Self(datetime.with_minute(0).unwrap_or(datetime))
+    }
+
+    /// Returns the datetime at midnight of the same day, in its own offset.
+    ///
+    /// If midnight does not exist in the timezone, the value is truncated to hours only.
+    pub fn truncated_to_days(&self) -> Self {
+        let datetime = self.truncated_to_hours().0;
+        Self(datetime.with_hour(0).unwrap_or(datetime))
+    }
+
+    /// Returns 00:00:00.000000000 of the same day, in its own offset. Same as `truncated_to_days`.
+    pub fn start_of_day(&self) -> Self {
+        self.truncated_to_days()
+    }
+
+    /// Returns 23:59:59.999999999 of the same day, in its own offset.
+    ///
+    /// Fields that do not exist at that local time, e.g. across a `Local` DST transition, are left unchanged.
+    pub fn end_of_day(&self) -> Self {
+        let datetime = self.0;
+        let datetime = datetime.with_hour(23).unwrap_or(datetime);
+        let datetime = datetime.with_minute(59).unwrap_or(datetime);
+        let datetime = datetime.with_second(59).unwrap_or(datetime);
+        Self(datetime.with_nanosecond(999_999_999).unwrap_or(datetime))
+    }
+
+    /// Truncates the fractional seconds to `digits` decimal places, e.g. `6` for microseconds.
+    ///
+    /// Useful to normalize values before persisting them to a store with coarser precision.
+    /// Digits above 9 leave the value unchanged.
+    pub fn truncate_subsec(&self, digits: u32) -> Self {
+        Self(self.0.trunc_subsecs(digits.min(9) as u16))
+    }
+
+    /// Compares the UTC instants, whatever the timezones and offsets of both values.
+    ///
+    /// Same as `Ord` but also across timezones, for use in `sort_by`.
+    pub fn cmp_instant<Tz2, const OTHER_OFFSET_HOURS: i32, const OTHER_OFFSET_MINUTES: i32>(
+        &self,
+        other: &DateTimeDefaultNow<Tz2, OTHER_OFFSET_HOURS, OTHER_OFFSET_MINUTES>,
+    ) -> core::cmp::Ordering
+    where
+        Tz2: TimeZone,
+        <Tz2 as TimeZone>::Offset: Copy,
+    {
+        self.0.naive_utc().cmp(&other.0.naive_utc())
+    }
+
+    /// Returns `(UTC timestamp in nanoseconds, offset in seconds)`, a sort key ordering by instant
+    /// and then by offset, deterministic across mixed offsets.
+    ///
+    /// Instants outside the `i64` nanosecond range (roughly years 1677 to 2262) saturate to
+    /// `i64::MIN` or `i64::MAX`, so they still sort before or after every representable key.
+    pub fn stable_key(&self) -> (i64, i32) {
+        let nanos = self
+            .0
+            .timestamp_nanos_opt()
+            .unwrap_or(if self.0.timestamp() < 0 {
+                i64::MIN
+            } else {
+                i64::MAX
+            });
+        (nanos, self.0.offset().fix().local_minus_utc())
+    }
+
+    /// Clamps the datetime into `[min, max]`.
+    ///
+    /// Unlike [`Ord::clamp`] this does not panic if `min > max`; the result is then `min`.
+    pub fn clamp_between(self, min: DateTime<Tz>, max: DateTime<Tz>) -> Self {
+        Self(self.0.min(max).max(min))
+    }
+
+    /// Adds a duration, clamping to the minimum or maximum representable datetime on overflow.
+    pub fn saturating_add(&self, duration: Duration) -> Self {
+        self.0.checked_add_signed(duration).map_or_else(
+            || {
+                let boundary = if duration < Duration::zero() {
+                    DateTime::<Utc>::MIN_UTC
+                } else {
+                    DateTime::<Utc>::MAX_UTC
+                };
+                Self(boundary.with_timezone(&self.0.timezone()))
+            },
+            Self,
         )
     }
-}
 
-impl Default for DateTimeDefaultNow<Utc, 0> {
-    #[cfg(not(test))]
-    fn default() -> Self {
-        Self(Utc::now())
+    /// Subtracts a duration, clamping to the minimum or maximum representable datetime on overflow.
+    pub fn saturating_sub(&self, duration: Duration) -> Self {
+        match duration.checked_mul(-1) {
+            Some(negated) => self.saturating_add(negated),
+            None => Self(DateTime::<Utc>::MAX_UTC.with_timezone(&self.0.timezone())),
+        }
     }
 
-    #[cfg(test)]
-    fn default() -> Self {
-        Self(Utc.datetime_from_str(NOW, "%Y/%m/%d %H:%M:%S%.9f").unwrap())
+    /// Adds a duration, returning `None` on overflow.
+    pub fn checked_add_signed(&self, duration: Duration) -> Option<Self> {
+        self.0.checked_add_signed(duration).map(Self)
+    }
+
+    /// Subtracts a duration, returning `None` on overflow.
+    pub fn checked_sub_signed(&self, duration: Duration) -> Option<Self> {
+        self.0.checked_sub_signed(duration).map(Self)
+    }
+
+    /// Adds calendar days, negative values going backwards.
+    ///
+    /// Returns `None` on overflow or if the resulting local time does not exist.
+    pub fn checked_add_days(&self, days: i64) -> Option<Self> {
+        let magnitude = Days::new(days.unsigned_abs());
+        if days < 0 {
+            self.0.checked_sub_days(magnitude)
+        } else {
+            self.0.checked_add_days(magnitude)
+        }
+        .map(Self)
+    }
+
+    /// Adds calendar months, negative values going backwards.
+    ///
+    /// The day is clamped to the last day of the resulting month if needed.
+    /// Returns `None` on overflow or if the resulting local time does not exist.
+    pub fn checked_add_months(&self, months: i32) -> Option<Self> {
+        let magnitude = Months::new(months.unsigned_abs());
+        if months < 0 {
+            self.0.checked_sub_months(magnitude)
+        } else {
+            self.0.checked_add_months(magnitude)
+        }
+        .map(Self)
     }
 }
 
-impl<Tz, const OFFSET_HOURS: i32> Deref for DateTimeDefaultNow<Tz, OFFSET_HOURS>
+impl<Tz, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32>
+    DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>
 where
     Tz: TimeZone,
     <Tz as TimeZone>::Offset: Copy,
+    Self: From<DateTime<Utc>>,
 {
-    type Target = DateTime<Tz>;
+    /// Returns the UNIX epoch, shown in the wrapper's timezone.
+    pub fn epoch() -> Self {
+        Self::from(DateTime::<Utc>::UNIX_EPOCH)
+    }
 
-    fn deref(&self) -> &Self::Target {
-        &self.0
+    /// Returns the minimum representable datetime, shown in the wrapper's timezone.
+    pub fn min_value() -> Self {
+        Self::from(DateTime::<Utc>::MIN_UTC)
+    }
+
+    /// Returns the maximum representable datetime, shown in the wrapper's timezone.
+    pub fn max_value() -> Self {
+        Self::from(DateTime::<Utc>::MAX_UTC)
     }
 }
 
-impl<Tz, const OFFSET_HOURS: i32> From<DateTime<Tz>> for DateTimeDefaultNow<Tz, OFFSET_HOURS>
-where
-    Tz: TimeZone,
-    <Tz as TimeZone>::Offset: Copy,
+impl<const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32>
+    DateTimeDefaultNow<FixedOffset, OFFSET_HOURS, OFFSET_MINUTES>
 {
-    fn from(datetime: DateTime<Tz>) -> Self {
-        Self(datetime)
+    /// Evaluated on first use of the offset, so an out-of-range offset is a compile error.
+    const VALID_OFFSET: () = {
+        assert!(
+            -12 <= OFFSET_HOURS && OFFSET_HOURS <= 14,
+            "OFFSET_HOURS must be within -12..=14"
+        );
+        assert!(
+            -24 * 60 < OFFSET_HOURS * 60 + OFFSET_MINUTES
+                && OFFSET_HOURS * 60 + OFFSET_MINUTES < 24 * 60,
+            "the total offset must be strictly within ±24 hours"
+        );
+    };
+
+    /// Returns the current time at the configured offset.
+    ///
+    /// `OFFSET_HOURS` must be within `-12..=14` and the total offset strictly within ±24 hours,
+    /// otherwise the program fails to compile.
+    #[cfg(feature = "std")]
+    pub fn now() -> Self {
+        Self(DateTimeDefaultNow::<Utc>::now().with_timezone(&Self::configured_offset()))
+    }
+
+    /// Returns the current time at the configured offset, truncated to whole seconds.
+    #[cfg(feature = "std")]
+    pub fn now_truncated() -> Self {
+        Self::now().truncated_to_seconds()
+    }
+
+    /// Returns the current time at the configured offset,
+    /// or an error instead of a compile error if the offset is out of range.
+    #[cfg(feature = "std")]
+    pub fn try_default() -> Result<Self, DefaultError> {
+        let total_minutes = OFFSET_HOURS * 60 + OFFSET_MINUTES;
+        (-12..=14)
+            .contains(&OFFSET_HOURS)
+            .then(|| FixedOffset::east_opt(total_minutes * 60))
+            .flatten()
+            .map(|offset| Self(DateTimeDefaultNow::<Utc>::now().with_timezone(&offset)))
+            .ok_or(DefaultError::OffsetOutOfRange {
+                hours: OFFSET_HOURS,
+                minutes: OFFSET_MINUTES,
+            })
+    }
+
+    /// Makes a new value from a UNIX timestamp, shown at the configured offset.
+    ///
+    /// Returns `None` on out-of-range `secs` or invalid `nsecs`.
+    pub fn from_timestamp(secs: i64, nsecs: u32) -> Option<Self> {
+        DateTimeDefaultNow::<Utc>::from_timestamp(secs, nsecs)
+            .map(|datetime| Self(datetime.with_timezone(&Self::configured_offset())))
+    }
+
+    /// Makes a new value from a wall-clock date and time at the configured offset.
+    ///
+    /// Returns `None` on invalid dates like February 30 or out-of-range fields.
+    pub fn from_ymd_hms(
+        year: i32,
+        month: u32,
+        day: u32,
+        hour: u32,
+        min: u32,
+        sec: u32,
+    ) -> Option<Self> {
+        Self::configured_offset()
+            .with_ymd_and_hms(year, month, day, hour, min, sec)
+            .single()
+            .map(Self)
+    }
+
+    /// Makes a new value from UNIX epoch milliseconds, shown at the configured offset.
+    ///
+    /// Returns `None` on out-of-range input.
+    pub fn from_timestamp_millis(millis: i64) -> Option<Self> {
+        DateTimeDefaultNow::<Utc>::from_timestamp_millis(millis)
+            .map(|datetime| Self(datetime.with_timezone(&Self::configured_offset())))
+    }
+
+    /// Makes a new value from UNIX epoch microseconds, shown at the configured offset.
+    ///
+    /// Returns `None` on out-of-range input.
+    pub fn from_timestamp_micros(micros: i64) -> Option<Self> {
+        DateTimeDefaultNow::<Utc>::from_timestamp_micros(micros)
+            .map(|datetime| Self(datetime.with_timezone(&Self::configured_offset())))
+    }
+
+    /// Stores a new instant, normalized to the configured offset.
+    ///
+    /// Unlike assigning through `DerefMut`, this keeps the offset matching the const parameters.
+    pub fn set(&mut self, datetime: DateTime<FixedOffset>) {
+        self.0 = datetime.with_timezone(&Self::configured_offset());
+    }
+
+    /// Returns a label like `UTC+09:00` for the value's offset, for self-describing logs.
+    pub fn zone_label(&self) -> String {
+        format!("UTC{}", self.0.offset())
+    }
+
+    /// Sets the value to the current time at the configured offset.
+    #[cfg(feature = "std")]
+    pub fn reset_to_now(&mut self) {
+        *self = Self::now();
+    }
+
+    /// Parses a datetime with a custom format, reading the wall-clock time at the configured offset.
+    ///
+    /// If the format carries an offset, e.g. `%z`, the datetime is parsed at that offset
+    /// and then converted to the configured one.
+    pub fn parse_from_str(s: &str, fmt: &str) -> chrono::ParseResult<Self> {
+        let mut parsed = Parsed::new();
+        format::parse(&mut parsed, s, StrftimeItems::new(fmt))?;
+        let offset = Self::configured_offset();
+        match parsed.offset {
+            Some(_) => parsed
+                .to_datetime()
+                .map(|datetime| Self(datetime.with_timezone(&offset))),
+            None => parsed.to_datetime_with_timezone(&offset).map(Self),
+        }
+    }
+
+    /// Formats the datetime as RFC 2822, e.g. `Tue, 11 Oct 2022 08:40:11 +0900`.
+    pub fn to_rfc2822(&self) -> String {
+        self.0.to_rfc2822()
+    }
+
+    /// Parses an RFC 2822 datetime such as an HTTP `Date:` header, converting it to the configured offset.
+    pub fn parse_rfc2822(s: &str) -> chrono::ParseResult<Self> {
+        DateTime::parse_from_rfc2822(s)
+            .map(|datetime| Self(datetime.with_timezone(&Self::configured_offset())))
+    }
+
+    /// Returns the configured `OFFSET_HOURS` and `OFFSET_MINUTES` as a `FixedOffset`.
+    ///
+    /// The offset of a particular value is still available as `offset()` through `Deref`,
+    /// and differs from this one for values converted with the generic `From<DateTime<FixedOffset>>`.
+    pub fn configured_offset() -> FixedOffset {
+        let () = Self::VALID_OFFSET;
+        FixedOffset::east_opt((OFFSET_HOURS * 60 + OFFSET_MINUTES) * 60)
+            .expect("offset is validated at compile time")
     }
 }
 
-impl<const OFFSET_HOURS: i32> std::str::FromStr for DateTimeDefaultNow<FixedOffset, OFFSET_HOURS> {
-    type Err = chrono::ParseError;
+impl DateTimeDefaultNow<FixedOffset, 0, 0> {
+    /// Parses an RFC 3339 datetime, keeping whatever offset the string carries.
+    ///
+    /// For inputs whose offset isn't known at compile time; the const offset stays `0`.
+    pub fn from_rfc3339_fixed(s: &str) -> chrono::ParseResult<Self> {
+        DateTime::parse_from_rfc3339(s).map(Self)
+    }
+}
 
-    fn from_str(s: &str) -> chrono::ParseResult<DateTimeDefaultNow<FixedOffset, OFFSET_HOURS>> {
-        DateTime::<FixedOffset>::from_str(s).map(DateTimeDefaultNow::from)
+#[cfg(feature = "std")]
+impl DateTimeDefaultNow<Local, 0, 0> {
+    /// Returns the current local time.
+    #[cfg(not(test))]
+    pub fn now() -> Self {
+        Self(
+            clock::test_clock_now()
+                .map(|now| now.with_timezone(&Local))
+                .unwrap_or_else(Local::now),
+        )
+    }
+
+    #[cfg(test)]
+    pub fn now() -> Self {
+        Self(
+            clock::test_clock_now()
+                .map(|now| now.with_timezone(&Local))
+                .unwrap_or_else(|| clock::test_mode_now().and_local_timezone(Local).unwrap()),
+        )
+    }
+
+    /// Returns the current local time, truncated to whole seconds.
+    pub fn now_truncated() -> Self {
+        Self::now().truncated_to_seconds()
+    }
+
+    /// Sets the value to the current local time.
+    pub fn reset_to_now(&mut self) {
+        *self = Self::now();
+    }
+
+    /// Returns a label like `UTC+09:00` for the value's local offset, for self-describing logs.
+    ///
+    /// The label is always the offset: chrono does not expose the name of the system zone.
+    pub fn zone_label(&self) -> String {
+        format!("UTC{}", self.0.offset().fix())
+    }
+
+    /// Makes a new value from a local wall-clock date and time.
+    ///
+    /// Returns `None` on invalid dates like February 30, out-of-range fields,
+    /// and wall-clock times skipped or repeated by a DST transition.
+    pub fn from_ymd_hms(
+        year: i32,
+        month: u32,
+        day: u32,
+        hour: u32,
+        min: u32,
+        sec: u32,
+    ) -> Option<Self> {
+        Local
+            .with_ymd_and_hms(year, month, day, hour, min, sec)
+            .single()
+            .map(Self)
+    }
+
+    /// Parses a datetime with a custom format, reading the wall-clock time as local time.
+    pub fn parse_from_str(s: &str, fmt: &str) -> chrono::ParseResult<Self> {
+        parse_with_timezone(s, fmt, &Local).map(Self)
     }
 }
 
-impl<const OFFSET_HOURS: i32> std::str::FromStr for DateTimeDefaultNow<Local, OFFSET_HOURS> {
-    type Err = chrono::ParseError;
+impl DateTimeDefaultNow<Utc, 0, 0> {
+    /// Returns the current UTC time.
+    #[cfg(all(feature = "std", not(test)))]
+    pub fn now() -> Self {
+        Self(clock::test_clock_now().unwrap_or_else(|| SystemClock.now_utc()))
+    }
 
-    fn from_str(s: &str) -> chrono::ParseResult<DateTimeDefaultNow<Local, OFFSET_HOURS>> {
-        DateTime::<Local>::from_str(s).map(DateTimeDefaultNow::from)
+    #[cfg(all(feature = "std", test))]
+    pub fn now() -> Self {
+        Self(clock::test_clock_now().unwrap_or_else(|| clock::test_mode_now().and_utc()))
+    }
+
+    /// Returns the current UTC time, truncated to whole seconds.
+    #[cfg(feature = "std")]
+    pub fn now_truncated() -> Self {
+        Self::now().truncated_to_seconds()
+    }
+
+    /// Keeps the instant but presents it at `H` hours, the display-time counterpart to storing in UTC.
+    ///
+    /// `H` must be within `-12..=14`, otherwise the program fails to compile.
+    pub fn present_at<const H: i32>(&self) -> DateTimeDefaultNow<FixedOffset, H> {
+        DateTimeDefaultNow::from(self.0)
+    }
+
+    /// Returns `UTC`, for self-describing logs.
+    pub fn zone_label(&self) -> String {
+        String::from("UTC")
+    }
+
+    /// Makes a new value from a UNIX timestamp.
+    ///
+    /// Returns `None` on out-of-range `secs` or invalid `nsecs`.
+    pub fn from_timestamp(secs: i64, nsecs: u32) -> Option<Self> {
+        Utc.timestamp_opt(secs, nsecs).single().map(Self)
+    }
+
+    /// Makes a new value from a UTC date and time.
+    ///
+    /// Returns `None` on invalid dates like February 30 or out-of-range fields.
+    pub fn from_ymd_hms(
+        year: i32,
+        month: u32,
+        day: u32,
+        hour: u32,
+        min: u32,
+        sec: u32,
+    ) -> Option<Self> {
+        Utc.with_ymd_and_hms(year, month, day, hour, min, sec)
+            .single()
+            .map(Self)
+    }
+
+    /// Makes a new value from UNIX epoch milliseconds.
+    ///
+    /// Returns `None` on out-of-range input.
+    pub fn from_timestamp_millis(millis: i64) -> Option<Self> {
+        DateTime::from_timestamp_millis(millis).map(Self)
+    }
+
+    /// Makes a new value from UNIX epoch microseconds.
+    ///
+    /// Returns `None` on out-of-range input.
+    pub fn from_timestamp_micros(micros: i64) -> Option<Self> {
+        DateTime::from_timestamp_micros(micros).map(Self)
+    }
+
+    /// Sets the value to the current UTC time.
+    #[cfg(feature = "std")]
+    pub fn reset_to_now(&mut self) {
+        *self = Self::now();
+    }
+
+    /// Parses a datetime with a custom format, reading the wall-clock time as UTC.
+    pub fn parse_from_str(s: &str, fmt: &str) -> chrono::ParseResult<Self> {
+        parse_with_timezone(s, fmt, &Utc).map(Self)
+    }
+
+    /// Formats the datetime as RFC 2822, e.g. `Mon, 10 Oct 2022 23:40:11 +0000`.
+    pub fn to_rfc2822(&self) -> String {
+        self.0.to_rfc2822()
+    }
+
+    /// Parses an RFC 2822 datetime such as an HTTP `Date:` header, converting it to UTC.
+    pub fn parse_rfc2822(s: &str) -> chrono::ParseResult<Self> {
+        DateTime::parse_from_rfc2822(s).map(|datetime| Self(datetime.with_timezone(&Utc)))
     }
 }
 
-impl<const OFFSET_HOURS: i32> std::str::FromStr for DateTimeDefaultNow<Utc, OFFSET_HOURS> {
-    type Err = chrono::ParseError;
+/// Returns the current time at `H` hours east of UTC, honoring the test clock.
+///
+/// Shorthand for `DateTimeDefaultNow::<FixedOffset, H>::now()`.
+///
+/// ```
+/// use chrono::FixedOffset;
+/// use datetime_default::now_in;
+///
+/// assert_eq!(now_in::<9>().offset(), &FixedOffset::east_opt(9 * 3600).unwrap());
+/// ```
+#[cfg(feature = "std")]
+pub fn now_in<const H: i32>() -> DateTimeDefaultNow<FixedOffset, H> {
+    DateTimeDefaultNow::<FixedOffset, H>::now()
+}
 
-    fn from_str(s: &str) -> chrono::ParseResult<DateTimeDefaultNow<Utc, OFFSET_HOURS>> {
-        DateTime::<Utc>::from_str(s).map(DateTimeDefaultNow::from)
+fn parse_with_timezone<Tz: TimeZone>(
+    s: &str,
+    fmt: &str,
+    tz: &Tz,
+) -> chrono::ParseResult<DateTime<Tz>> {
+    let mut parsed = Parsed::new();
+    format::parse(&mut parsed, s, StrftimeItems::new(fmt))?;
+    parsed.to_datetime_with_timezone(tz)
+}
+
+#[cfg(feature = "std")]
+impl<const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> Default
+    for DateTimeDefaultNow<FixedOffset, OFFSET_HOURS, OFFSET_MINUTES>
+{
+    fn default() -> Self {
+        Self::now()
     }
 }
 
-impl<Tz, const OFFSET_HOURS: i32> std::cmp::PartialEq<DateTime<Tz>>
-    for DateTimeDefaultNow<Tz, OFFSET_HOURS>
+/// Defaults to [`CurrentTime::now`], covering `Utc`, `Local` and custom timezones.
+impl<Tz> Default for DateTimeDefaultNow<Tz, 0, 0>
 where
-    Tz: TimeZone,
+    Tz: CurrentTime,
     <Tz as TimeZone>::Offset: Copy,
 {
-    fn eq(&self, other: &DateTime<Tz>) -> bool {
-        self.0.eq(other)
+    fn default() -> Self {
+        Self(Tz::now())
     }
 }
 
-impl<Tz, const OFFSET_HOURS: i32> std::cmp::PartialEq<DateTimeDefaultNow<Tz, OFFSET_HOURS>>
-    for DateTime<Tz>
+impl<Tz, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> Deref
+    for DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>
 where
     Tz: TimeZone,
     <Tz as TimeZone>::Offset: Copy,
 {
-    fn eq(&self, other: &DateTimeDefaultNow<Tz, OFFSET_HOURS>) -> bool {
-        self.eq(&other.0)
+    type Target = DateTime<Tz>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
     }
 }
 
-impl<Tz, const OFFSET_HOURS: i32> std::cmp::PartialOrd<DateTime<Tz>>
-    for DateTimeDefaultNow<Tz, OFFSET_HOURS>
+impl<Tz, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> DerefMut
+    for DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>
 where
     Tz: TimeZone,
     <Tz as TimeZone>::Offset: Copy,
 {
-    fn partial_cmp(&self, other: &DateTime<Tz>) -> Option<std::cmp::Ordering> {
-        self.0.partial_cmp(other)
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
     }
 }
 
-impl<Tz, const OFFSET_HOURS: i32> std::cmp::PartialOrd<DateTimeDefaultNow<Tz, OFFSET_HOURS>>
-    for DateTime<Tz>
+impl<Tz, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> From<DateTime<Tz>>
+    for DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>
 where
     Tz: TimeZone,
     <Tz as TimeZone>::Offset: Copy,
 {
-    fn partial_cmp(
-        &self,
-        other: &DateTimeDefaultNow<Tz, OFFSET_HOURS>,
-    ) -> Option<std::cmp::Ordering> {
-        self.partial_cmp(&other.0)
+    fn from(datetime: DateTime<Tz>) -> Self {
+        Self(datetime)
     }
 }
 
-impl<Tz, const OFFSET_HOURS: i32> std::ops::Add<Duration> for DateTimeDefaultNow<Tz, OFFSET_HOURS>
-where
-    Tz: TimeZone,
-    <Tz as TimeZone>::Offset: Copy,
+/// Presents a UTC instant at the configured offset.
+impl<const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> From<DateTime<Utc>>
+    for DateTimeDefaultNow<FixedOffset, OFFSET_HOURS, OFFSET_MINUTES>
 {
-    type Output = DateTimeDefaultNow<Tz, OFFSET_HOURS>;
+    fn from(datetime: DateTime<Utc>) -> Self {
+        Self(datetime.with_timezone(&Self::configured_offset()))
+    }
+}
 
-    #[inline]
-    fn add(self, rhs: Duration) -> Self::Output {
-        DateTimeDefaultNow(self.0.add(rhs))
+#[cfg(feature = "std")]
+impl<const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> From<DateTime<Utc>>
+    for DateTimeDefaultNow<Local, OFFSET_HOURS, OFFSET_MINUTES>
+{
+    fn from(datetime: DateTime<Utc>) -> Self {
+        Self(datetime.with_timezone(&Local))
     }
 }
 
-impl<Tz, const OFFSET_HOURS: i32> std::ops::Add<FixedOffset>
-    for DateTimeDefaultNow<Tz, OFFSET_HOURS>
-where
-    Tz: TimeZone,
-    <Tz as TimeZone>::Offset: Copy,
+#[cfg(feature = "std")]
+impl<const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> From<SystemTime>
+    for DateTimeDefaultNow<Utc, OFFSET_HOURS, OFFSET_MINUTES>
+{
+    fn from(time: SystemTime) -> Self {
+        Self(DateTime::from(time))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> From<SystemTime>
+    for DateTimeDefaultNow<Local, OFFSET_HOURS, OFFSET_MINUTES>
 {
-    type Output = DateTimeDefaultNow<Tz, OFFSET_HOURS>;
+    fn from(time: SystemTime) -> Self {
+        Self(DateTime::from(time))
+    }
+}
 
-    #[inline]
-    fn add(self, rhs: FixedOffset) -> Self::Output {
-        DateTimeDefaultNow(self.0.add(rhs))
+/// Presents a `SystemTime` at the configured offset.
+#[cfg(feature = "std")]
+impl<const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> From<SystemTime>
+    for DateTimeDefaultNow<FixedOffset, OFFSET_HOURS, OFFSET_MINUTES>
+{
+    fn from(time: SystemTime) -> Self {
+        Self::from(DateTime::<Utc>::from(time))
     }
 }
 
-impl<Tz, const OFFSET_HOURS: i32> std::ops::AddAssign<Duration>
-    for DateTimeDefaultNow<Tz, OFFSET_HOURS>
+#[cfg(feature = "std")]
+impl<Tz, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32>
+    From<DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>> for SystemTime
 where
     Tz: TimeZone,
     <Tz as TimeZone>::Offset: Copy,
 {
-    #[inline]
-    fn add_assign(&mut self, rhs: Duration) {
-        self.0.add_assign(rhs);
+    fn from(datetime: DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>) -> Self {
+        datetime.0.into()
     }
 }
 
-impl<Tz, const OFFSET_HOURS: i32> std::ops::Sub<DateTimeDefaultNow<Tz, OFFSET_HOURS>>
-    for DateTimeDefaultNow<Tz, OFFSET_HOURS>
+impl<Tz, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32>
+    From<DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>> for DateTime<Tz>
 where
     Tz: TimeZone,
     <Tz as TimeZone>::Offset: Copy,
 {
-    type Output = Duration;
-
-    #[inline]
-    fn sub(self, rhs: DateTimeDefaultNow<Tz, OFFSET_HOURS>) -> Duration {
-        self.0.sub(rhs.0)
+    fn from(datetime: DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>) -> Self {
+        datetime.0
     }
 }
 
-impl<Tz, const OFFSET_HOURS: i32> std::ops::Sub<DateTime<Tz>>
-    for DateTimeDefaultNow<Tz, OFFSET_HOURS>
+impl<Tz, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> AsRef<DateTime<Tz>>
+    for DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>
 where
     Tz: TimeZone,
     <Tz as TimeZone>::Offset: Copy,
 {
-    type Output = Duration;
+    fn as_ref(&self) -> &DateTime<Tz> {
+        &self.0
+    }
+}
+
+impl<const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> core::str::FromStr
+    for DateTimeDefaultNow<FixedOffset, OFFSET_HOURS, OFFSET_MINUTES>
+{
+    type Err = chrono::ParseError;
+
+    fn from_str(
+        s: &str,
+    ) -> chrono::ParseResult<DateTimeDefaultNow<FixedOffset, OFFSET_HOURS, OFFSET_MINUTES>> {
+        DateTime::<FixedOffset>::from_str(s).map(DateTimeDefaultNow::from)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> core::str::FromStr
+    for DateTimeDefaultNow<Local, OFFSET_HOURS, OFFSET_MINUTES>
+{
+    type Err = chrono::ParseError;
+
+    fn from_str(
+        s: &str,
+    ) -> chrono::ParseResult<DateTimeDefaultNow<Local, OFFSET_HOURS, OFFSET_MINUTES>> {
+        DateTime::<Local>::from_str(s).map(DateTimeDefaultNow::from)
+    }
+}
+
+impl<const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> core::str::FromStr
+    for DateTimeDefaultNow<Utc, OFFSET_HOURS, OFFSET_MINUTES>
+{
+    type Err = chrono::ParseError;
+
+    fn from_str(
+        s: &str,
+    ) -> chrono::ParseResult<DateTimeDefaultNow<Utc, OFFSET_HOURS, OFFSET_MINUTES>> {
+        DateTime::<Utc>::from_str(s).map(DateTimeDefaultNow::from)
+    }
+}
+
+/// Parses an RFC 3339 datetime like [`FromStr`](core::str::FromStr).
+impl<Tz, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> TryFrom<&str>
+    for DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+    Self: core::str::FromStr<Err = chrono::ParseError>,
+{
+    type Error = chrono::ParseError;
+
+    fn try_from(s: &str) -> chrono::ParseResult<Self> {
+        s.parse()
+    }
+}
+
+/// Parses an RFC 3339 datetime like [`FromStr`](core::str::FromStr).
+impl<Tz, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> TryFrom<String>
+    for DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+    Self: core::str::FromStr<Err = chrono::ParseError>,
+{
+    type Error = chrono::ParseError;
+
+    fn try_from(s: String) -> chrono::ParseResult<Self> {
+        s.parse()
+    }
+}
+
+// The timezone and const offsets only affect the presentation of the value,
+// so wrappers with different timezones or offsets compare by instant.
+impl<
+        Tz,
+        Tz2,
+        const OFFSET_HOURS: i32,
+        const OFFSET_MINUTES: i32,
+        const OTHER_OFFSET_HOURS: i32,
+        const OTHER_OFFSET_MINUTES: i32,
+    > core::cmp::PartialEq<DateTimeDefaultNow<Tz2, OTHER_OFFSET_HOURS, OTHER_OFFSET_MINUTES>>
+    for DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+    Tz2: TimeZone,
+    <Tz2 as TimeZone>::Offset: Copy,
+{
+    fn eq(
+        &self,
+        other: &DateTimeDefaultNow<Tz2, OTHER_OFFSET_HOURS, OTHER_OFFSET_MINUTES>,
+    ) -> bool {
+        self.0.eq(&other.0)
+    }
+}
+
+impl<Tz, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> core::cmp::Eq
+    for DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+}
+
+impl<
+        Tz,
+        Tz2,
+        const OFFSET_HOURS: i32,
+        const OFFSET_MINUTES: i32,
+        const OTHER_OFFSET_HOURS: i32,
+        const OTHER_OFFSET_MINUTES: i32,
+    > core::cmp::PartialOrd<DateTimeDefaultNow<Tz2, OTHER_OFFSET_HOURS, OTHER_OFFSET_MINUTES>>
+    for DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+    Tz2: TimeZone,
+    <Tz2 as TimeZone>::Offset: Copy,
+{
+    fn partial_cmp(
+        &self,
+        other: &DateTimeDefaultNow<Tz2, OTHER_OFFSET_HOURS, OTHER_OFFSET_MINUTES>,
+    ) -> Option<core::cmp::Ordering> {
+        self.0.partial_cmp(&other.0)
+    }
+}
+
+impl<Tz, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> core::cmp::Ord
+    for DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+// `Eq`, `PartialEq<DateTime<Tz>>` and `Hash` all look only at the inner `DateTime`,
+// so a wrapper and the `DateTime` it equals always hash identically.
+impl<Tz, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> core::hash::Hash
+    for DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.0.hash(state)
+    }
+}
+
+impl<Tz, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> core::cmp::PartialEq<DateTime<Tz>>
+    for DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    fn eq(&self, other: &DateTime<Tz>) -> bool {
+        self.0.eq(other)
+    }
+}
+
+impl<Tz, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32>
+    core::cmp::PartialEq<DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>> for DateTime<Tz>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    fn eq(&self, other: &DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>) -> bool {
+        self.eq(&other.0)
+    }
+}
+
+impl<Tz, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> core::cmp::PartialOrd<DateTime<Tz>>
+    for DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    fn partial_cmp(&self, other: &DateTime<Tz>) -> Option<core::cmp::Ordering> {
+        self.0.partial_cmp(other)
+    }
+}
+
+impl<Tz, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32>
+    core::cmp::PartialOrd<DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>> for DateTime<Tz>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    fn partial_cmp(
+        &self,
+        other: &DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>,
+    ) -> Option<core::cmp::Ordering> {
+        self.partial_cmp(&other.0)
+    }
+}
+
+/// Compares the UTC wall-clock time, i.e. `naive_utc()`, with the naive datetime.
+impl<const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> core::cmp::PartialEq<NaiveDateTime>
+    for DateTimeDefaultNow<Utc, OFFSET_HOURS, OFFSET_MINUTES>
+{
+    fn eq(&self, other: &NaiveDateTime) -> bool {
+        self.0.naive_utc().eq(other)
+    }
+}
+
+impl<const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32>
+    core::cmp::PartialEq<DateTimeDefaultNow<Utc, OFFSET_HOURS, OFFSET_MINUTES>> for NaiveDateTime
+{
+    fn eq(&self, other: &DateTimeDefaultNow<Utc, OFFSET_HOURS, OFFSET_MINUTES>) -> bool {
+        self.eq(&other.0.naive_utc())
+    }
+}
+
+/// Compares the UTC wall-clock time, i.e. `naive_utc()`, with the naive datetime.
+impl<const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> core::cmp::PartialOrd<NaiveDateTime>
+    for DateTimeDefaultNow<Utc, OFFSET_HOURS, OFFSET_MINUTES>
+{
+    fn partial_cmp(&self, other: &NaiveDateTime) -> Option<core::cmp::Ordering> {
+        self.0.naive_utc().partial_cmp(other)
+    }
+}
+
+impl<const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32>
+    core::cmp::PartialOrd<DateTimeDefaultNow<Utc, OFFSET_HOURS, OFFSET_MINUTES>> for NaiveDateTime
+{
+    fn partial_cmp(
+        &self,
+        other: &DateTimeDefaultNow<Utc, OFFSET_HOURS, OFFSET_MINUTES>,
+    ) -> Option<core::cmp::Ordering> {
+        self.partial_cmp(&other.0.naive_utc())
+    }
+}
+
+/// Compares the wall-clock time in the wrapper's own zone, i.e. `naive_local()`, with midnight of the date,
+/// so only 00:00:00 on that day is equal and any later time that day is greater.
+impl<Tz, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> core::cmp::PartialEq<NaiveDate>
+    for DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    fn eq(&self, other: &NaiveDate) -> bool {
+        self.0.naive_local().eq(&other.and_time(NaiveTime::MIN))
+    }
+}
+
+impl<Tz, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32>
+    core::cmp::PartialEq<DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>> for NaiveDate
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    fn eq(&self, other: &DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>) -> bool {
+        other.eq(self)
+    }
+}
+
+/// Compares the wall-clock time in the wrapper's own zone, i.e. `naive_local()`, with midnight of the date.
+impl<Tz, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> core::cmp::PartialOrd<NaiveDate>
+    for DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    fn partial_cmp(&self, other: &NaiveDate) -> Option<core::cmp::Ordering> {
+        self.0
+            .naive_local()
+            .partial_cmp(&other.and_time(NaiveTime::MIN))
+    }
+}
+
+impl<Tz, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32>
+    core::cmp::PartialOrd<DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>> for NaiveDate
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    fn partial_cmp(
+        &self,
+        other: &DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>,
+    ) -> Option<core::cmp::Ordering> {
+        other.partial_cmp(self).map(core::cmp::Ordering::reverse)
+    }
+}
+
+impl<Tz, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> core::ops::Add<Duration>
+    for DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    type Output = DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>;
+
+    #[inline]
+    fn add(self, rhs: Duration) -> Self::Output {
+        DateTimeDefaultNow(self.0.add(rhs))
+    }
+}
+
+impl<Tz, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> core::ops::Add<FixedOffset>
+    for DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    type Output = DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>;
+
+    #[inline]
+    fn add(self, rhs: FixedOffset) -> Self::Output {
+        DateTimeDefaultNow(self.0.add(rhs))
+    }
+}
+
+impl<Tz, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> core::ops::AddAssign<Duration>
+    for DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    #[inline]
+    fn add_assign(&mut self, rhs: Duration) {
+        self.0.add_assign(rhs);
+    }
+}
+
+/// Same as [`DateTimeDefaultNow::signed_duration_since`].
+impl<Tz, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32>
+    core::ops::Sub<DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>>
+    for DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    type Output = Duration;
+
+    #[inline]
+    fn sub(self, rhs: DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>) -> Duration {
+        self.0.sub(rhs.0)
+    }
+}
+
+impl<Tz, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> core::ops::Sub<DateTime<Tz>>
+    for DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    type Output = Duration;
 
     #[inline]
     fn sub(self, rhs: DateTime<Tz>) -> Duration {
@@ -219,12 +1298,13 @@ where
     }
 }
 
-impl<Tz, const OFFSET_HOURS: i32> std::ops::Sub<Duration> for DateTimeDefaultNow<Tz, OFFSET_HOURS>
+impl<Tz, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> core::ops::Sub<Duration>
+    for DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>
 where
     Tz: TimeZone,
     <Tz as TimeZone>::Offset: Copy,
 {
-    type Output = DateTimeDefaultNow<Tz, OFFSET_HOURS>;
+    type Output = DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>;
 
     #[inline]
     fn sub(self, rhs: Duration) -> Self::Output {
@@ -232,13 +1312,13 @@ where
     }
 }
 
-impl<Tz, const OFFSET_HOURS: i32> std::ops::Sub<FixedOffset>
-    for DateTimeDefaultNow<Tz, OFFSET_HOURS>
+impl<Tz, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> core::ops::Sub<FixedOffset>
+    for DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>
 where
     Tz: TimeZone,
     <Tz as TimeZone>::Offset: Copy,
 {
-    type Output = DateTimeDefaultNow<Tz, OFFSET_HOURS>;
+    type Output = DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>;
 
     #[inline]
     fn sub(self, rhs: FixedOffset) -> Self::Output {
@@ -246,8 +1326,8 @@ where
     }
 }
 
-impl<Tz, const OFFSET_HOURS: i32> std::ops::SubAssign<Duration>
-    for DateTimeDefaultNow<Tz, OFFSET_HOURS>
+impl<Tz, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> core::ops::SubAssign<Duration>
+    for DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>
 where
     Tz: TimeZone,
     <Tz as TimeZone>::Offset: Copy,
@@ -258,7 +1338,8 @@ where
     }
 }
 
-impl<Tz, const OFFSET_HOURS: i32> chrono::DurationRound for DateTimeDefaultNow<Tz, OFFSET_HOURS>
+impl<Tz, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> chrono::DurationRound
+    for DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>
 where
     Tz: TimeZone,
     <Tz as TimeZone>::Offset: Copy,
@@ -276,9 +1357,16 @@ where
             .duration_trunc(duration)
             .map(DateTimeDefaultNow::from)
     }
+
+    fn duration_round_up(self, duration: Duration) -> Result<Self, Self::Err> {
+        self.0
+            .duration_round_up(duration)
+            .map(DateTimeDefaultNow::from)
+    }
 }
 
-impl<Tz, const OFFSET_HOURS: i32> chrono::Datelike for DateTimeDefaultNow<Tz, OFFSET_HOURS>
+impl<Tz, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> chrono::Datelike
+    for DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>
 where
     Tz: TimeZone,
     <Tz as TimeZone>::Offset: Copy,
@@ -321,42 +1409,55 @@ where
     }
 
     #[inline]
-    fn with_year(&self, year: i32) -> Option<DateTimeDefaultNow<Tz, OFFSET_HOURS>> {
+    fn with_year(&self, year: i32) -> Option<DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>> {
         self.0.with_year(year).map(DateTimeDefaultNow::from)
     }
 
     #[inline]
-    fn with_month(&self, month: u32) -> Option<DateTimeDefaultNow<Tz, OFFSET_HOURS>> {
+    fn with_month(
+        &self,
+        month: u32,
+    ) -> Option<DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>> {
         self.0.with_month(month).map(DateTimeDefaultNow::from)
     }
 
     #[inline]
-    fn with_month0(&self, month0: u32) -> Option<DateTimeDefaultNow<Tz, OFFSET_HOURS>> {
+    fn with_month0(
+        &self,
+        month0: u32,
+    ) -> Option<DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>> {
         self.0.with_month0(month0).map(DateTimeDefaultNow::from)
     }
 
     #[inline]
-    fn with_day(&self, day: u32) -> Option<DateTimeDefaultNow<Tz, OFFSET_HOURS>> {
+    fn with_day(&self, day: u32) -> Option<DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>> {
         self.0.with_day(day).map(DateTimeDefaultNow::from)
     }
 
     #[inline]
-    fn with_day0(&self, day0: u32) -> Option<DateTimeDefaultNow<Tz, OFFSET_HOURS>> {
+    fn with_day0(&self, day0: u32) -> Option<DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>> {
         self.0.with_day0(day0).map(DateTimeDefaultNow::from)
     }
 
     #[inline]
-    fn with_ordinal(&self, ordinal: u32) -> Option<DateTimeDefaultNow<Tz, OFFSET_HOURS>> {
+    fn with_ordinal(
+        &self,
+        ordinal: u32,
+    ) -> Option<DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>> {
         self.0.with_ordinal(ordinal).map(DateTimeDefaultNow::from)
     }
 
     #[inline]
-    fn with_ordinal0(&self, ordinal0: u32) -> Option<DateTimeDefaultNow<Tz, OFFSET_HOURS>> {
+    fn with_ordinal0(
+        &self,
+        ordinal0: u32,
+    ) -> Option<DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>> {
         self.0.with_ordinal0(ordinal0).map(DateTimeDefaultNow::from)
     }
 }
 
-impl<Tz, const OFFSET_HOURS: i32> chrono::Timelike for DateTimeDefaultNow<Tz, OFFSET_HOURS>
+impl<Tz, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> chrono::Timelike
+    for DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>
 where
     Tz: TimeZone,
     <Tz as TimeZone>::Offset: Copy,
@@ -379,42 +1480,86 @@ where
     }
 
     #[inline]
-    fn with_hour(&self, hour: u32) -> Option<DateTimeDefaultNow<Tz, OFFSET_HOURS>> {
+    fn with_hour(&self, hour: u32) -> Option<DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>> {
         self.0.with_hour(hour).map(DateTimeDefaultNow::from)
     }
 
     #[inline]
-    fn with_minute(&self, min: u32) -> Option<DateTimeDefaultNow<Tz, OFFSET_HOURS>> {
+    fn with_minute(
+        &self,
+        min: u32,
+    ) -> Option<DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>> {
         self.0.with_minute(min).map(DateTimeDefaultNow::from)
     }
 
     #[inline]
-    fn with_second(&self, sec: u32) -> Option<DateTimeDefaultNow<Tz, OFFSET_HOURS>> {
+    fn with_second(
+        &self,
+        sec: u32,
+    ) -> Option<DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>> {
         self.0.with_second(sec).map(DateTimeDefaultNow::from)
     }
 
     #[inline]
-    fn with_nanosecond(&self, nano: u32) -> Option<DateTimeDefaultNow<Tz, OFFSET_HOURS>> {
+    fn with_nanosecond(
+        &self,
+        nano: u32,
+    ) -> Option<DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>> {
         self.0.with_nanosecond(nano).map(DateTimeDefaultNow::from)
     }
 }
 
-impl<Tz, const OFFSET_HOURS: i32> std::fmt::Display for DateTimeDefaultNow<Tz, OFFSET_HOURS>
+/// Prints the wrapper type with its non-zero const offsets,
+/// e.g. `DateTimeDefaultNow<FixedOffset, 9>(2022-10-11T08:40:11.695164300+09:00)`.
+impl<Tz, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> core::fmt::Debug
+    for DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        let type_name = core::any::type_name::<Tz>();
+        let path = type_name.split('<').next().unwrap_or(type_name);
+        let timezone = &type_name[path.rfind("::").map_or(0, |i| i + 2)..];
+
+        write!(f, "DateTimeDefaultNow<{timezone}")?;
+        if OFFSET_HOURS != 0 || OFFSET_MINUTES != 0 {
+            write!(f, ", {OFFSET_HOURS}")?;
+        }
+        if OFFSET_MINUTES != 0 {
+            write!(f, ", {OFFSET_MINUTES}")?;
+        }
+        write!(f, ">({:?})", self.0)
+    }
+}
+
+impl<Tz, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> core::fmt::Display
+    for DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>
 where
     Tz: TimeZone,
-    <Tz as TimeZone>::Offset: Copy + std::fmt::Display,
+    <Tz as TimeZone>::Offset: Copy + core::fmt::Display,
 {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         self.0.fmt(f)
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod tests {
 
-    use chrono::{DateTime, FixedOffset, Local, TimeZone, Utc};
+    use std::{
+        collections::{hash_map::DefaultHasher, HashSet},
+        hash::{Hash, Hasher},
+        time::SystemTime,
+    };
 
-    use crate::DateTimeDefaultNow;
+    use chrono::{
+        DateTime, Datelike, Duration, FixedOffset, Local, NaiveDate, NaiveDateTime, Timelike, Utc,
+    };
+
+    use crate::{
+        clear_test_clock, now_in, set_test_clock, DateTimeDefaultNow, DefaultError, FixedClock,
+    };
 
     #[derive(Default)]
     struct Test {
@@ -445,50 +1590,462 @@ mod tests {
     }
 
     #[test]
-    fn fixed_offset() {
-        let datetime = DateTimeDefaultNow::<FixedOffset, 9>::default();
-        println!("{datetime:?}");
-
-        // assert!(false);
+    fn now() {
+        assert_eq!(
+            DateTimeDefaultNow::<Utc>::now(),
+            DateTimeDefaultNow::<Utc>::default()
+        );
+        assert_eq!(
+            *DateTimeDefaultNow::<Local>::now(),
+            *DateTimeDefaultNow::<Local>::default()
+        );
+        assert_eq!(
+            DateTimeDefaultNow::<FixedOffset, 9>::now(),
+            DateTimeDefaultNow::<FixedOffset, 9>::default()
+        );
     }
 
     #[test]
-    fn use_datetime_method() {
-        let datetime = DateTimeDefaultNow::<Utc>::default();
+    fn from_timestamp() {
+        assert_eq!(
+            DateTimeDefaultNow::<Utc>::from_timestamp(1665445211, 695164300).unwrap(),
+            DateTimeDefaultNow::<Utc>::default()
+        );
+        assert_eq!(
+            DateTimeDefaultNow::<Utc>::from_timestamp(0, 0).unwrap(),
+            DateTime::<Utc>::UNIX_EPOCH
+        );
+    }
 
-        datetime.date();
+    #[test]
+    fn from_timestamp_out_of_range() {
+        assert_eq!(DateTimeDefaultNow::<Utc>::from_timestamp(i64::MAX, 0), None);
+        assert_eq!(
+            DateTimeDefaultNow::<Utc>::from_timestamp(0, 2_000_000_000),
+            None
+        );
+        assert_eq!(
+            DateTimeDefaultNow::<FixedOffset, 9>::from_timestamp(i64::MIN, 0),
+            None
+        );
     }
 
     #[test]
-    fn convert_from() {
-        let now = Local::now();
-        let datetime = DateTimeDefaultNow::<Local>::from(now);
+    fn from_timestamp_fixed_offset() {
+        let datetime = DateTimeDefaultNow::<FixedOffset, 9>::from_timestamp(0, 0).unwrap();
 
-        assert_eq!(datetime, now)
+        assert_eq!(*datetime, DateTime::<Utc>::UNIX_EPOCH);
+        assert_eq!(datetime.hour(), 9);
+        assert_eq!(datetime.to_rfc3339(), "1970-01-01T09:00:00+09:00");
     }
 
     #[test]
-    fn convert_into() {
-        let now = Local::now();
-        let datetime: DateTimeDefaultNow<Local> = now.into();
+    fn from_timestamp_millis_and_micros() {
+        let datetime = DateTimeDefaultNow::<Utc>::default();
 
-        assert_eq!(datetime, now)
+        assert_eq!(
+            DateTimeDefaultNow::<Utc>::from_timestamp_millis(1665445211695).unwrap(),
+            datetime.truncate_subsec(3)
+        );
+        assert_eq!(
+            DateTimeDefaultNow::<Utc>::from_timestamp_micros(1665445211695164).unwrap(),
+            datetime.truncate_subsec(6)
+        );
+        assert_eq!(
+            DateTimeDefaultNow::<Utc>::from_timestamp_millis(datetime.timestamp_millis())
+                .unwrap()
+                .timestamp_millis(),
+            datetime.timestamp_millis()
+        );
+
+        let fixed = DateTimeDefaultNow::<FixedOffset, 9>::from_timestamp_millis(0).unwrap();
+        assert_eq!(fixed.to_rfc3339(), "1970-01-01T09:00:00+09:00");
+        assert_eq!(
+            DateTimeDefaultNow::<FixedOffset, 9>::from_timestamp_micros(1665445211695164)
+                .unwrap()
+                .timestamp_micros(),
+            datetime.timestamp_micros()
+        );
     }
 
     #[test]
-    fn equation1() {
-        let now = Local::now();
-        let datetime: DateTimeDefaultNow<Local> = now.into();
-        assert!(datetime == now)
+    fn from_timestamp_millis_and_micros_out_of_range() {
+        assert_eq!(
+            DateTimeDefaultNow::<Utc>::from_timestamp_millis(i64::MAX),
+            None
+        );
+        assert_eq!(
+            DateTimeDefaultNow::<Utc>::from_timestamp_micros(i64::MIN),
+            None
+        );
+        assert_eq!(
+            DateTimeDefaultNow::<FixedOffset, 9>::from_timestamp_millis(i64::MIN),
+            None
+        );
+        assert_eq!(
+            DateTimeDefaultNow::<FixedOffset, 9>::from_timestamp_micros(i64::MAX),
+            None
+        );
     }
 
     #[test]
-    fn equation2() {
+    fn reset_to_now() {
+        let mut datetime = DateTimeDefaultNow::<Utc>::from(DateTime::<Utc>::UNIX_EPOCH);
+        let before = DateTimeDefaultNow::<Utc>::now();
+
+        datetime.reset_to_now();
+
+        assert!(datetime >= before)
+    }
+
+    #[test]
+    fn reset_to_now_timezones() {
+        let mut local = DateTimeDefaultNow::<Local>::from(DateTime::<Utc>::UNIX_EPOCH);
+        let mut tokyo = DateTimeDefaultNow::<FixedOffset, 9>::from(DateTime::<Utc>::UNIX_EPOCH);
+
+        local.reset_to_now();
+        tokyo.reset_to_now();
+
+        assert_eq!(*local, *DateTimeDefaultNow::<Local>::now());
+        assert_eq!(tokyo, DateTimeDefaultNow::<FixedOffset, 9>::now());
+    }
+
+    #[test]
+    fn fixed_offset() {
+        let datetime = DateTimeDefaultNow::<FixedOffset, 9>::default();
+        println!("{datetime:?}");
+
+        // assert!(false);
+    }
+
+    #[test]
+    fn fixed_offset_minutes() {
+        let datetime = DateTimeDefaultNow::<FixedOffset, 5, 30>::default();
+
+        assert_eq!(
+            datetime.offset(),
+            &FixedOffset::east_opt(5 * 3600 + 30 * 60).unwrap()
+        );
+        assert_eq!(
+            datetime,
+            DateTimeDefaultNow::<Utc>::default().with_timezone(datetime.offset())
+        );
+    }
+
+    #[test]
+    fn fixed_offset_minutes_nepal() {
+        let datetime = DateTimeDefaultNow::<FixedOffset, 5, 45>::default();
+
+        assert_eq!(
+            datetime.offset(),
+            &FixedOffset::east_opt(5 * 3600 + 45 * 60).unwrap()
+        );
+    }
+
+    #[test]
+    fn negative_offset() {
+        assert_eq!(
+            DateTimeDefaultNow::<FixedOffset, -5>::default().offset(),
+            &FixedOffset::west_opt(5 * 3600).unwrap()
+        );
+        assert_eq!(
+            DateTimeDefaultNow::<FixedOffset, -8>::default().offset(),
+            &FixedOffset::west_opt(8 * 3600).unwrap()
+        );
+        assert_eq!(
+            DateTimeDefaultNow::<FixedOffset, -12>::default().offset(),
+            &FixedOffset::west_opt(12 * 3600).unwrap()
+        );
+    }
+
+    #[test]
+    fn offset_bounds() {
+        assert_eq!(
+            DateTimeDefaultNow::<FixedOffset, 14>::default().offset(),
+            &FixedOffset::east_opt(14 * 3600).unwrap()
+        );
+        assert_eq!(
+            DateTimeDefaultNow::<FixedOffset, -12, -59>::default().offset(),
+            &FixedOffset::west_opt(12 * 3600 + 59 * 60).unwrap()
+        );
+    }
+
+    #[test]
+    fn negative_offset_minutes() {
+        assert_eq!(
+            DateTimeDefaultNow::<FixedOffset, -3, -30>::default().offset(),
+            &FixedOffset::west_opt(3 * 3600 + 30 * 60).unwrap()
+        );
+    }
+
+    #[test]
+    fn use_datetime_method() {
+        let datetime = DateTimeDefaultNow::<Utc>::default();
+
+        let _ = datetime.date_naive();
+    }
+
+    #[test]
+    fn mutate_through_deref() {
+        let mut datetime = DateTimeDefaultNow::<Utc>::default();
+        let now = Utc::now();
+
+        *datetime = now;
+
+        assert_eq!(datetime, now)
+    }
+
+    #[test]
+    fn use_datetime_mut_method() {
+        fn add_hour(datetime: &mut DateTime<Utc>) {
+            *datetime += Duration::hours(1);
+        }
+
+        let mut datetime = DateTimeDefaultNow::<Utc>::default();
+        let expected = *datetime + Duration::hours(1);
+
+        add_hour(&mut datetime);
+
+        assert_eq!(datetime, expected)
+    }
+
+    #[test]
+    fn to_timezone() {
+        let datetime = DateTimeDefaultNow::<Utc>::default();
+        let local: DateTimeDefaultNow<Local> = datetime.to_timezone(&Local);
+
+        assert_eq!(*local, *datetime);
+        assert_eq!(local.to_timezone(&Utc), datetime)
+    }
+
+    #[test]
+    fn into_inner() {
+        let now = Utc::now();
+
+        assert_eq!(DateTimeDefaultNow::<Utc>::from(now).into_inner(), now)
+    }
+
+    #[test]
+    fn as_inner() {
+        let now = Utc::now();
+
+        assert_eq!(DateTimeDefaultNow::<Utc>::from(now).as_inner(), &now)
+    }
+
+    #[test]
+    fn convert_from() {
+        let now = Local::now();
+        let datetime = DateTimeDefaultNow::<Local>::from(now);
+
+        assert_eq!(datetime, now)
+    }
+
+    #[test]
+    fn convert_into() {
+        let now = Local::now();
+        let datetime: DateTimeDefaultNow<Local> = now.into();
+
+        assert_eq!(datetime, now)
+    }
+
+    #[test]
+    fn convert_from_utc() {
+        let now = Utc::now();
+        let datetime = DateTimeDefaultNow::<FixedOffset, 9>::from(now);
+
+        assert_eq!(*datetime, now);
+        assert_eq!(datetime.offset(), &FixedOffset::east_opt(9 * 3600).unwrap());
+        assert_eq!(*DateTimeDefaultNow::<Local>::from(now), now)
+    }
+
+    #[test]
+    fn convert_into_datetime() {
+        let datetime = DateTimeDefaultNow::<Utc>::default();
+        let inner: DateTime<Utc> = datetime.into();
+
+        assert_eq!(datetime, inner)
+    }
+
+    #[test]
+    fn as_ref() {
+        fn takes(datetime: impl AsRef<DateTime<Utc>>) -> DateTime<Utc> {
+            *datetime.as_ref()
+        }
+
+        let datetime = DateTimeDefaultNow::<Utc>::default();
+
+        assert_eq!(takes(datetime), *datetime)
+    }
+
+    #[test]
+    fn from_str() {
+        let datetime: DateTimeDefaultNow<Utc> = "2022-10-10T23:40:11Z".parse().unwrap();
+
+        assert_eq!(
+            datetime,
+            NaiveDateTime::parse_from_str("2022/10/10 23:40:11", "%Y/%m/%d %H:%M:%S")
+                .unwrap()
+                .and_utc()
+        )
+    }
+
+    #[test]
+    fn from_str_fixed_offset() {
+        let datetime: DateTimeDefaultNow<FixedOffset, 9> =
+            "2022-10-11T08:40:11+09:00".parse().unwrap();
+
+        assert_eq!(
+            datetime,
+            DateTime::parse_from_rfc3339("2022-10-10T23:40:11Z").unwrap()
+        )
+    }
+
+    #[test]
+    fn from_str_local() {
+        let datetime: DateTimeDefaultNow<Local> = "2022-10-10T23:40:11Z".parse().unwrap();
+
+        assert_eq!(
+            *datetime,
+            DateTime::parse_from_rfc3339("2022-10-10T23:40:11Z").unwrap()
+        )
+    }
+
+    #[test]
+    fn from_str_invalid() {
+        assert!("2022/10/10".parse::<DateTimeDefaultNow<Utc>>().is_err())
+    }
+
+    #[test]
+    fn try_from_str() {
+        let utc = DateTimeDefaultNow::<Utc>::try_from("2022-10-10T23:40:11Z").unwrap();
+        let fixed = DateTimeDefaultNow::<FixedOffset, 9>::try_from(String::from(
+            "2022-10-11T08:40:11+09:00",
+        ))
+        .unwrap();
+        let local = DateTimeDefaultNow::<Local>::try_from("2022-10-10T23:40:11Z").unwrap();
+
+        assert_eq!(
+            utc,
+            "2022-10-10T23:40:11Z"
+                .parse::<DateTimeDefaultNow<Utc>>()
+                .unwrap()
+        );
+        assert_eq!(fixed, utc);
+        assert_eq!(local, utc);
+    }
+
+    #[test]
+    fn try_from_str_invalid() {
+        assert!(DateTimeDefaultNow::<Utc>::try_from("2022/10/10").is_err());
+        assert!(DateTimeDefaultNow::<FixedOffset, 9>::try_from(String::from("now")).is_err());
+        assert!(DateTimeDefaultNow::<Local>::try_from("").is_err())
+    }
+
+    #[test]
+    fn add_duration() {
+        let datetime = DateTimeDefaultNow::<Utc>::default();
+
+        assert_eq!(
+            datetime + Duration::hours(1),
+            *datetime + Duration::hours(1)
+        )
+    }
+
+    #[test]
+    fn sub_duration() {
+        let datetime = DateTimeDefaultNow::<FixedOffset, 9>::default();
+        let result: DateTimeDefaultNow<FixedOffset, 9> = datetime - Duration::hours(1);
+
+        assert_eq!(result, *datetime - Duration::hours(1));
+        assert_eq!(result.offset(), datetime.offset())
+    }
+
+    #[test]
+    fn sub_wrapper() {
+        let datetime = DateTimeDefaultNow::<Utc>::default();
+
+        assert_eq!(
+            (datetime + Duration::hours(1)) - datetime,
+            Duration::hours(1)
+        )
+    }
+
+    #[test]
+    fn equation1() {
+        let now = Local::now();
+        let datetime: DateTimeDefaultNow<Local> = now.into();
+        assert!(datetime == now)
+    }
+
+    #[test]
+    fn equation2() {
         let now = Local::now();
         let datetime: DateTimeDefaultNow<Local> = now.into();
         assert!(now == datetime)
     }
 
+    #[test]
+    fn to_string() {
+        let datetime = DateTimeDefaultNow::<Utc>::default();
+
+        assert_eq!(datetime.to_string(), (*datetime).to_string())
+    }
+
+    #[test]
+    fn display_bound() {
+        fn display(value: impl std::fmt::Display) -> String {
+            format!("{value}")
+        }
+
+        let datetime = DateTimeDefaultNow::<FixedOffset, 9>::default();
+
+        assert_eq!(display(datetime), (*datetime).to_string())
+    }
+
+    #[test]
+    fn hash_like_datetime() {
+        fn hash(value: impl Hash) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            value.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        let datetime = DateTimeDefaultNow::<Utc>::default();
+
+        assert_eq!(hash(datetime), hash(*datetime))
+    }
+
+    #[test]
+    fn hash_set_lookup() {
+        let datetime = DateTimeDefaultNow::<Utc>::default();
+        let mut set = HashSet::<DateTime<Utc>>::new();
+
+        set.insert(datetime.into());
+
+        assert!(set.contains(&*datetime));
+        assert!(set.contains(&DateTimeDefaultNow::<Utc>::default().into()))
+    }
+
+    #[test]
+    fn equation_across_offsets() {
+        let utc = DateTimeDefaultNow::<FixedOffset, 0>::default();
+        let tokyo = DateTimeDefaultNow::<FixedOffset, 9>::default();
+
+        assert_ne!(utc.offset(), tokyo.offset());
+        assert!(utc == tokyo);
+        assert!(tokyo == utc)
+    }
+
+    #[test]
+    fn comparing_across_offsets() {
+        let utc = DateTimeDefaultNow::<FixedOffset, 0>::default();
+        let tokyo = DateTimeDefaultNow::<FixedOffset, 9>::default() + Duration::seconds(1);
+
+        assert!(utc < tokyo);
+        assert!(tokyo > utc);
+        assert!(DateTimeDefaultNow::<FixedOffset, 5, 30>::default() <= utc)
+    }
+
     #[test]
     fn comparing1() {
         assert!(DateTimeDefaultNow::<Local>::default() <= Local::now())
@@ -497,10 +2054,1027 @@ mod tests {
     #[test]
     fn comparing2() {
         assert!(
-            Local
-                .datetime_from_str("2022/10/10 00:00:00", "%Y/%m/%d %H:%M:%S")
+            NaiveDateTime::parse_from_str("2022/10/10 00:00:00", "%Y/%m/%d %H:%M:%S")
+                .unwrap()
+                .and_local_timezone(Local)
                 .unwrap()
                 <= DateTimeDefaultNow::<Local>::default()
         )
     }
+
+    #[test]
+    fn comparing_naive_date() {
+        let datetime = DateTimeDefaultNow::<FixedOffset, 9>::default();
+        let today = NaiveDate::from_ymd_opt(2022, 10, 11).unwrap();
+        let yesterday = NaiveDate::from_ymd_opt(2022, 10, 10).unwrap();
+
+        assert!(datetime > today);
+        assert!(today < datetime);
+        assert!(datetime > yesterday);
+        assert_ne!(datetime, today);
+        assert!(datetime < NaiveDate::from_ymd_opt(2022, 10, 12).unwrap());
+
+        let midnight: DateTimeDefaultNow<FixedOffset, 9> =
+            "2022-10-11T00:00:00+09:00".parse().unwrap();
+
+        assert_eq!(midnight, today);
+        assert_eq!(today, midnight)
+    }
+
+    #[test]
+    fn elapsed_since() {
+        let earlier = DateTimeDefaultNow::<Utc>::default();
+        let later = earlier + Duration::minutes(5);
+
+        assert_eq!(later.elapsed_since(&earlier), Duration::minutes(5));
+        assert_eq!(earlier.elapsed_since(&later), Duration::minutes(-5))
+    }
+
+    #[test]
+    fn total_elapsed() {
+        let first = DateTimeDefaultNow::<Utc>::default();
+        let second = first + Duration::seconds(90);
+        let third = second + Duration::hours(2);
+
+        assert_eq!(
+            DateTimeDefaultNow::total_elapsed(&[first, second, third]),
+            third - first
+        );
+        assert_eq!(
+            DateTimeDefaultNow::total_elapsed(&[first]),
+            Duration::zero()
+        );
+        assert_eq!(
+            DateTimeDefaultNow::<Utc>::total_elapsed(&[]),
+            Duration::zero()
+        )
+    }
+
+    #[test]
+    fn truncated_to_granularities() {
+        let datetime = DateTimeDefaultNow::<Utc>::default();
+        let truncated = |s| {
+            NaiveDateTime::parse_from_str(s, "%Y/%m/%d %H:%M:%S")
+                .unwrap()
+                .and_utc()
+        };
+
+        assert_eq!(
+            datetime.truncated_to_seconds(),
+            truncated("2022/10/10 23:40:11")
+        );
+        assert_eq!(
+            datetime.truncated_to_minutes(),
+            truncated("2022/10/10 23:40:00")
+        );
+        assert_eq!(
+            datetime.truncated_to_hours(),
+            truncated("2022/10/10 23:00:00")
+        );
+        assert_eq!(
+            datetime.truncated_to_days(),
+            truncated("2022/10/10 00:00:00")
+        )
+    }
+
+    #[test]
+    fn truncated_to_days_keeps_offset() {
+        let datetime = DateTimeDefaultNow::<FixedOffset, 9>::default().truncated_to_days();
+
+        assert_eq!(datetime.offset(), &FixedOffset::east_opt(9 * 3600).unwrap());
+        assert_eq!(
+            datetime,
+            DateTime::parse_from_rfc3339("2022-10-11T00:00:00+09:00").unwrap()
+        )
+    }
+
+    #[test]
+    fn checked_add_signed() {
+        let datetime = DateTimeDefaultNow::<FixedOffset, 9>::default();
+
+        assert_eq!(
+            datetime.checked_add_signed(Duration::hours(16)).unwrap(),
+            DateTime::parse_from_rfc3339("2022-10-12T00:40:11.695164300+09:00").unwrap()
+        );
+        assert_eq!(
+            datetime.checked_sub_signed(Duration::hours(9)).unwrap(),
+            DateTime::parse_from_rfc3339("2022-10-10T23:40:11.695164300+09:00").unwrap()
+        );
+        assert_eq!(
+            datetime
+                .checked_add_signed(Duration::hours(16))
+                .unwrap()
+                .offset(),
+            datetime.offset()
+        )
+    }
+
+    #[test]
+    fn checked_add_signed_overflow() {
+        let near_max =
+            DateTimeDefaultNow::<Utc>::from(DateTime::<Utc>::MAX_UTC) - Duration::seconds(1);
+        let near_min =
+            DateTimeDefaultNow::<Utc>::from(DateTime::<Utc>::MIN_UTC) + Duration::seconds(1);
+
+        assert!(near_max.checked_add_signed(Duration::days(1)).is_none());
+        assert!(near_min.checked_sub_signed(Duration::days(1)).is_none());
+        assert!(near_max.checked_add_signed(Duration::seconds(1)).is_some())
+    }
+
+    #[test]
+    fn checked_add_days() {
+        let datetime = DateTimeDefaultNow::<FixedOffset, 9>::default();
+
+        assert_eq!(
+            datetime.checked_add_days(3).unwrap(),
+            DateTime::parse_from_rfc3339("2022-10-14T08:40:11.695164300+09:00").unwrap()
+        );
+        assert_eq!(
+            datetime.checked_add_days(-11).unwrap(),
+            DateTime::parse_from_rfc3339("2022-09-30T08:40:11.695164300+09:00").unwrap()
+        );
+        assert_eq!(
+            datetime.checked_add_days(3).unwrap().offset(),
+            datetime.offset()
+        )
+    }
+
+    #[test]
+    fn checked_add_months_across_year() {
+        let datetime = DateTimeDefaultNow::<Utc>::default();
+
+        assert_eq!(
+            datetime.checked_add_months(3).unwrap(),
+            NaiveDateTime::parse_from_str("2023/01/10 23:40:11.695164300", "%Y/%m/%d %H:%M:%S%.9f")
+                .unwrap()
+                .and_utc()
+        );
+        assert_eq!(
+            datetime.checked_add_months(-10).unwrap(),
+            NaiveDateTime::parse_from_str("2021/12/10 23:40:11.695164300", "%Y/%m/%d %H:%M:%S%.9f")
+                .unwrap()
+                .and_utc()
+        )
+    }
+
+    #[test]
+    fn checked_add_overflow() {
+        let datetime = DateTimeDefaultNow::<Utc>::from(DateTime::<Utc>::MAX_UTC);
+
+        assert!(datetime.checked_add_days(1).is_none());
+        assert!(datetime.checked_add_months(1).is_none());
+        assert!(DateTimeDefaultNow::<Utc>::from(DateTime::<Utc>::MIN_UTC)
+            .checked_add_days(-1)
+            .is_none())
+    }
+
+    #[test]
+    fn convert_from_system_time() {
+        let datetime = DateTimeDefaultNow::<Utc>::from(SystemTime::UNIX_EPOCH);
+
+        assert_eq!(datetime, DateTime::<Utc>::UNIX_EPOCH);
+        assert_eq!(SystemTime::from(datetime), SystemTime::UNIX_EPOCH)
+    }
+
+    #[test]
+    fn convert_from_system_time_timezones() {
+        let local = DateTimeDefaultNow::<Local>::from(SystemTime::UNIX_EPOCH);
+        let tokyo = DateTimeDefaultNow::<FixedOffset, 9>::from(SystemTime::UNIX_EPOCH);
+
+        assert_eq!(local.timestamp(), 0);
+        assert_eq!(tokyo.offset(), &FixedOffset::east_opt(9 * 3600).unwrap());
+        assert_eq!(SystemTime::from(tokyo), SystemTime::UNIX_EPOCH)
+    }
+
+    #[test]
+    fn system_time_roundtrip() {
+        let datetime = DateTimeDefaultNow::<Utc>::default();
+
+        assert_eq!(
+            DateTimeDefaultNow::<Utc>::from(SystemTime::from(datetime)),
+            datetime
+        )
+    }
+
+    #[test]
+    fn rfc2822_roundtrip() {
+        let header = "Mon, 10 Oct 2022 23:40:11 +0000";
+        let datetime = DateTimeDefaultNow::<Utc>::parse_rfc2822(header).unwrap();
+
+        assert_eq!(
+            datetime,
+            DateTimeDefaultNow::<Utc>::default().truncated_to_seconds()
+        );
+        assert_eq!(datetime.to_rfc2822(), header)
+    }
+
+    #[test]
+    fn rfc2822_fixed_offset() {
+        let datetime =
+            DateTimeDefaultNow::<FixedOffset, 9>::parse_rfc2822("Tue, 11 Oct 2022 08:40:11 +0900")
+                .unwrap();
+
+        assert_eq!(datetime.offset(), &FixedOffset::east_opt(9 * 3600).unwrap());
+        assert_eq!(
+            DateTimeDefaultNow::<FixedOffset, 9>::default()
+                .truncated_to_seconds()
+                .to_rfc2822(),
+            "Tue, 11 Oct 2022 08:40:11 +0900"
+        );
+        assert!(DateTimeDefaultNow::<Utc>::parse_rfc2822("2022-10-10T23:40:11Z").is_err())
+    }
+
+    #[test]
+    fn rfc2822_fixed_offset_mismatch() {
+        let datetime =
+            DateTimeDefaultNow::<FixedOffset, 9>::parse_rfc2822("Mon, 10 Oct 2022 23:40:11 +0000")
+                .unwrap();
+
+        assert_eq!(
+            datetime.offset(),
+            &DateTimeDefaultNow::<FixedOffset, 9>::configured_offset()
+        );
+        assert_eq!(datetime.to_rfc2822(), "Tue, 11 Oct 2022 08:40:11 +0900");
+    }
+
+    #[test]
+    fn from_rfc3339_fixed() {
+        let tokyo = DateTimeDefaultNow::from_rfc3339_fixed("2022-10-11T08:40:11+09:00").unwrap();
+        let new_york = DateTimeDefaultNow::from_rfc3339_fixed("2022-10-10T18:40:11-05:00").unwrap();
+
+        assert_eq!(tokyo.offset(), &FixedOffset::east_opt(9 * 3600).unwrap());
+        assert_eq!(new_york.offset(), &FixedOffset::west_opt(5 * 3600).unwrap());
+        assert_eq!(tokyo, new_york);
+        assert_eq!(tokyo.to_rfc3339(), "2022-10-11T08:40:11+09:00");
+        assert!(DateTimeDefaultNow::from_rfc3339_fixed("2022/10/10 23:40:11").is_err())
+    }
+
+    #[test]
+    fn test_mode_now_same_wall_clock() {
+        assert_eq!(
+            DateTimeDefaultNow::<Utc>::default().naive_utc(),
+            DateTimeDefaultNow::<Local>::default().naive_local()
+        )
+    }
+
+    #[test]
+    fn approx_eq() {
+        let datetime = DateTimeDefaultNow::<Utc>::default();
+        let close = datetime + Duration::milliseconds(300);
+        let far = datetime - Duration::seconds(5);
+
+        assert!(datetime.approx_eq(&close, Duration::seconds(1)));
+        assert!(close.approx_eq(&datetime, Duration::seconds(1)));
+        assert!(datetime.approx_eq(&(datetime + Duration::seconds(1)), Duration::seconds(1)));
+        assert!(!datetime.approx_eq(&far, Duration::seconds(1)));
+        assert!(!far.approx_eq(&datetime, Duration::seconds(1)))
+    }
+
+    #[test]
+    fn comparing_naive_datetime() {
+        let datetime = DateTimeDefaultNow::<Utc>::default();
+        let naive = |s| NaiveDateTime::parse_from_str(s, "%Y/%m/%d %H:%M:%S%.9f").unwrap();
+        let earlier = naive("2022/10/10 23:40:11.000000000");
+        let later = naive("2022/10/11 00:00:00.000000000");
+
+        assert!(datetime > earlier);
+        assert!(datetime < later);
+        assert!(earlier < datetime);
+        assert!(later > datetime);
+        assert!(datetime == naive("2022/10/10 23:40:11.695164300"));
+        assert!(naive("2022/10/10 23:40:11.695164300") == datetime);
+        assert!(datetime != earlier)
+    }
+
+    #[test]
+    fn timestamp_units() {
+        let datetime = DateTimeDefaultNow::<Utc>::default();
+
+        assert_eq!(datetime.timestamp_millis(), 1_665_445_211_695);
+        assert_eq!(datetime.timestamp_micros(), 1_665_445_211_695_164);
+        assert_eq!(
+            datetime.timestamp_nanos_opt(),
+            Some(1_665_445_211_695_164_300)
+        );
+        assert_eq!(
+            DateTimeDefaultNow::<FixedOffset, 9>::default().timestamp_millis(),
+            1_665_445_211_695
+        );
+        assert_eq!(
+            DateTimeDefaultNow::<Utc>::from(DateTime::<Utc>::MAX_UTC).timestamp_nanos_opt(),
+            None
+        )
+    }
+
+    #[test]
+    fn to_fixed() {
+        let local = DateTimeDefaultNow::<Local>::default();
+        let tokyo = local.to_fixed::<9>();
+
+        assert_eq!(tokyo.offset(), &FixedOffset::east_opt(9 * 3600).unwrap());
+        assert_eq!(tokyo.timestamp_nanos_opt(), local.timestamp_nanos_opt());
+        assert_eq!(
+            DateTimeDefaultNow::<Utc>::default().to_fixed::<-5>(),
+            DateTime::parse_from_rfc3339("2022-10-10T18:40:11.695164300-05:00").unwrap()
+        )
+    }
+
+    #[test]
+    fn step_iter() {
+        let days: Vec<_> = DateTimeDefaultNow::<FixedOffset, 9>::default()
+            .step_iter(Duration::days(1))
+            .take(5)
+            .map(|datetime| datetime.date_naive().to_string())
+            .collect();
+
+        assert_eq!(
+            days,
+            [
+                "2022-10-11",
+                "2022-10-12",
+                "2022-10-13",
+                "2022-10-14",
+                "2022-10-15"
+            ]
+        )
+    }
+
+    #[test]
+    fn step_iter_stops_on_overflow() {
+        let start = DateTimeDefaultNow::<Utc>::from(DateTime::<Utc>::MAX_UTC) - Duration::days(2);
+
+        assert_eq!(start.step_iter(Duration::days(1)).count(), 3)
+    }
+
+    #[test]
+    fn debug_shows_type_and_offset() {
+        assert_eq!(
+            format!("{:?}", DateTimeDefaultNow::<FixedOffset, 9>::default()),
+            "DateTimeDefaultNow<FixedOffset, 9>(2022-10-11T08:40:11.695164300+09:00)"
+        );
+        assert_eq!(
+            format!("{:?}", DateTimeDefaultNow::<FixedOffset, 5, 30>::default()),
+            "DateTimeDefaultNow<FixedOffset, 5, 30>(2022-10-11T05:10:11.695164300+05:30)"
+        );
+        assert_eq!(
+            format!("{:?}", DateTimeDefaultNow::<Utc>::default()),
+            "DateTimeDefaultNow<Utc>(2022-10-10T23:40:11.695164300Z)"
+        );
+        assert!(format!("{:?}", DateTimeDefaultNow::<Local>::default())
+            .starts_with("DateTimeDefaultNow<Local>("))
+    }
+
+    #[test]
+    fn to_local() {
+        let tokyo = DateTimeDefaultNow::<FixedOffset, 9>::default();
+        let local = tokyo.to_local();
+
+        assert_eq!(local.naive_utc(), tokyo.naive_utc());
+        assert_eq!(
+            local.naive_local(),
+            tokyo.with_timezone(&Local).naive_local()
+        )
+    }
+
+    #[test]
+    fn map() {
+        let datetime = DateTimeDefaultNow::<FixedOffset, 9>::default()
+            .map(|datetime| datetime.with_year(2000).unwrap());
+
+        assert_eq!(
+            datetime,
+            DateTime::parse_from_rfc3339("2000-10-11T08:40:11.695164300+09:00").unwrap()
+        );
+        assert_eq!(datetime.offset(), &FixedOffset::east_opt(9 * 3600).unwrap())
+    }
+
+    #[test]
+    fn parse_from_str() {
+        let fmt = "%Y/%m/%d %H:%M:%S%.9f";
+
+        assert_eq!(
+            DateTimeDefaultNow::<Utc>::parse_from_str("2022/10/10 23:40:11.695164300", fmt)
+                .unwrap(),
+            DateTimeDefaultNow::<Utc>::default()
+        );
+        assert_eq!(
+            DateTimeDefaultNow::<Local>::parse_from_str("2022/10/10 23:40:11.695164300", fmt)
+                .unwrap(),
+            DateTimeDefaultNow::<Local>::default()
+        );
+        assert_eq!(
+            DateTimeDefaultNow::<FixedOffset, 9>::parse_from_str(
+                "2022/10/11 08:40:11.695164300",
+                fmt
+            )
+            .unwrap(),
+            DateTimeDefaultNow::<FixedOffset, 9>::default()
+        )
+    }
+
+    #[test]
+    fn parse_from_str_errors() {
+        assert!(DateTimeDefaultNow::<Utc>::parse_from_str("2022/10/10", "%Y/%m/%d %H:%M").is_err());
+        assert_eq!(
+            DateTimeDefaultNow::<FixedOffset, 9>::parse_from_str(
+                "2022/10/11 08:40 +0900",
+                "%Y/%m/%d %H:%M %z"
+            )
+            .unwrap(),
+            DateTime::parse_from_rfc3339("2022-10-11T08:40:00+09:00").unwrap()
+        )
+    }
+
+    #[test]
+    fn parse_from_str_converts_offset() {
+        let datetime = DateTimeDefaultNow::<FixedOffset, 9>::parse_from_str(
+            "2022/10/10 23:40 +0000",
+            "%Y/%m/%d %H:%M %z",
+        )
+        .unwrap();
+
+        assert_eq!(
+            datetime,
+            DateTime::parse_from_rfc3339("2022-10-10T23:40:00Z").unwrap()
+        );
+        assert_eq!(datetime.to_rfc3339(), "2022-10-11T08:40:00+09:00")
+    }
+
+    #[test]
+    fn offset_accessors() {
+        assert_eq!(DateTimeDefaultNow::<FixedOffset, 9>::offset_hours(), 9);
+        assert_eq!(DateTimeDefaultNow::<FixedOffset, 9>::offset_minutes(), 0);
+        assert_eq!(
+            DateTimeDefaultNow::<FixedOffset, -3, -30>::offset_minutes(),
+            -30
+        );
+        assert_eq!(DateTimeDefaultNow::<Utc>::offset_hours(), 0);
+        assert_eq!(
+            DateTimeDefaultNow::<FixedOffset, 5, 30>::configured_offset(),
+            FixedOffset::east_opt(5 * 3600 + 30 * 60).unwrap()
+        );
+        assert_eq!(
+            DateTimeDefaultNow::<FixedOffset, 5, 30>::configured_offset(),
+            *DateTimeDefaultNow::<FixedOffset, 5, 30>::default().offset()
+        )
+    }
+
+    #[test]
+    fn saturating_add() {
+        let max = DateTimeDefaultNow::<FixedOffset, 9>::from(DateTime::<Utc>::MAX_UTC);
+        let near_max = max - Duration::days(1);
+
+        assert_eq!(near_max.saturating_add(Duration::days(365_000)), max);
+        assert_eq!(
+            near_max.saturating_add(Duration::days(365_000)).offset(),
+            max.offset()
+        );
+        assert_eq!(
+            near_max.saturating_add(Duration::hours(1)),
+            near_max + Duration::hours(1)
+        )
+    }
+
+    #[test]
+    fn saturating_sub() {
+        let min = DateTimeDefaultNow::<Utc>::from(DateTime::<Utc>::MIN_UTC);
+        let datetime = DateTimeDefaultNow::<Utc>::default();
+
+        assert_eq!(datetime.saturating_sub(Duration::MAX), min);
+        assert_eq!(datetime.saturating_add(Duration::MIN), min);
+        assert_eq!(
+            datetime.saturating_sub(Duration::MIN),
+            DateTime::<Utc>::MAX_UTC
+        );
+        assert_eq!(
+            datetime.saturating_sub(Duration::days(1)),
+            datetime - Duration::days(1)
+        )
+    }
+
+    #[test]
+    fn truncate_subsec() {
+        let datetime = DateTimeDefaultNow::<Utc>::default();
+
+        assert_eq!(datetime.nanosecond(), 695_164_300);
+        assert_eq!(datetime.truncate_subsec(3).nanosecond(), 695_000_000);
+        assert_eq!(datetime.truncate_subsec(6).nanosecond(), 695_164_000);
+        assert_eq!(datetime.truncate_subsec(0).nanosecond(), 0);
+        assert_eq!(datetime.truncate_subsec(12), datetime)
+    }
+
+    #[test]
+    fn eq_across_timezones() {
+        let utc = DateTimeDefaultNow::<Utc>::default();
+        let fixed = DateTimeDefaultNow::<FixedOffset, 9>::from(*utc);
+
+        assert_eq!(utc, fixed);
+        assert_eq!(fixed, utc);
+        assert_ne!(fixed, utc + Duration::nanoseconds(1));
+        assert!(fixed < utc + Duration::nanoseconds(1));
+        assert!(utc - Duration::nanoseconds(1) < fixed);
+        assert_eq!(utc.partial_cmp(&fixed), Some(core::cmp::Ordering::Equal));
+    }
+
+    #[test]
+    fn eq_local_and_utc() {
+        let utc = DateTimeDefaultNow::<Utc>::default();
+        let local = utc.to_local();
+
+        assert_eq!(local, utc);
+        assert_eq!(utc, local);
+        assert!(local < utc + Duration::nanoseconds(1));
+        assert!(utc - Duration::nanoseconds(1) < local);
+        assert_eq!(local.partial_cmp(&utc), Some(core::cmp::Ordering::Equal));
+    }
+
+    #[test]
+    fn until_now_and_since_now() {
+        let now = DateTimeDefaultNow::<Utc>::default();
+        set_test_clock(FixedClock(*now));
+
+        let past = DateTimeDefaultNow::<FixedOffset, 9>::from(*now - Duration::hours(1));
+        let future = DateTimeDefaultNow::<Local>::from(*now + Duration::minutes(5));
+
+        assert_eq!(past.until_now(), Duration::hours(1));
+        assert_eq!(past.since_now(), -Duration::hours(1));
+        assert_eq!(future.until_now(), -Duration::minutes(5));
+        assert_eq!(future.since_now(), Duration::minutes(5));
+        assert_eq!(now.until_now(), Duration::zero());
+
+        clear_test_clock();
+    }
+
+    #[test]
+    fn clamp_between() {
+        let datetime = DateTimeDefaultNow::<FixedOffset, 9>::default();
+        let min = *datetime - Duration::hours(1);
+        let max = *datetime + Duration::hours(1);
+
+        assert_eq!((datetime - Duration::days(1)).clamp_between(min, max), min);
+        assert_eq!(datetime.clamp_between(min, max), datetime);
+        assert_eq!((datetime + Duration::days(1)).clamp_between(min, max), max);
+        assert_eq!(datetime.clamp_between(max, min), max)
+    }
+
+    #[test]
+    fn format() {
+        assert_eq!(
+            DateTimeDefaultNow::<Utc>::default().format("%Y-%m-%d"),
+            "2022-10-10"
+        );
+        assert_eq!(
+            DateTimeDefaultNow::<FixedOffset, 9>::default().format("%Y-%m-%d %H:%M %z"),
+            "2022-10-11 08:40 +0900"
+        )
+    }
+
+    #[test]
+    fn to_rfc3339_opts() {
+        use chrono::SecondsFormat;
+
+        let utc = DateTimeDefaultNow::<Utc>::default();
+        let tokyo = DateTimeDefaultNow::<FixedOffset, 9>::default();
+
+        assert_eq!(
+            utc.to_rfc3339_opts(SecondsFormat::Millis, true),
+            "2022-10-10T23:40:11.695Z"
+        );
+        assert_eq!(
+            utc.to_rfc3339_opts(SecondsFormat::Secs, false),
+            "2022-10-10T23:40:11+00:00"
+        );
+        assert_eq!(
+            tokyo.to_rfc3339_opts(SecondsFormat::Millis, true),
+            "2022-10-11T08:40:11.695+09:00"
+        );
+        assert_eq!(
+            tokyo.to_rfc3339_opts(SecondsFormat::Nanos, true),
+            "2022-10-11T08:40:11.695164300+09:00"
+        )
+    }
+
+    #[test]
+    fn to_filename_string() {
+        let utc = DateTimeDefaultNow::<Utc>::default();
+        let fixed = DateTimeDefaultNow::<FixedOffset, -3, -30>::default();
+        let local = DateTimeDefaultNow::<Local>::default();
+
+        assert_eq!(utc.to_filename_string(), "20221010T234011Z");
+        assert_eq!(fixed.to_filename_string(), "20221010T201011-0330");
+        assert_eq!(
+            DateTimeDefaultNow::<FixedOffset>::default().to_filename_string(),
+            "20221010T234011Z"
+        );
+
+        for (earlier, later) in [
+            (
+                utc.to_filename_string(),
+                (utc + Duration::seconds(1)).to_filename_string(),
+            ),
+            (
+                fixed.to_filename_string(),
+                (fixed + Duration::minutes(1)).to_filename_string(),
+            ),
+            (
+                local.to_filename_string(),
+                (local + Duration::hours(1)).to_filename_string(),
+            ),
+        ] {
+            assert!(!earlier.contains(':') && !later.contains(':'));
+            assert!(earlier < later);
+        }
+    }
+
+    #[test]
+    fn iso_week_and_weekday() {
+        let utc = DateTimeDefaultNow::<Utc>::default();
+        let fixed = DateTimeDefaultNow::<FixedOffset, 9>::default();
+
+        assert_eq!(utc.weekday(), chrono::Weekday::Mon);
+        assert_eq!(utc.iso_week().week(), 41);
+        assert_eq!(utc.iso_week().year(), 2022);
+        assert_eq!(fixed.weekday(), chrono::Weekday::Tue);
+        assert_eq!(fixed.iso_week(), utc.iso_week())
+    }
+
+    #[test]
+    fn humanize() {
+        let now = DateTimeDefaultNow::<Utc>::default();
+        set_test_clock(FixedClock(*now));
+
+        assert_eq!((now - Duration::minutes(90)).humanize(), "an hour ago");
+        assert_eq!((now - Duration::hours(3)).humanize(), "3 hours ago");
+        assert_eq!((now - Duration::seconds(45)).humanize(), "45 seconds ago");
+        assert_eq!((now + Duration::days(2)).humanize(), "in 2 days");
+        assert_eq!((now + Duration::seconds(61)).humanize(), "in a minute");
+        assert_eq!(
+            DateTimeDefaultNow::<FixedOffset, 9>::from(*now - Duration::days(1)).humanize(),
+            "a day ago"
+        );
+        assert_eq!(now.humanize(), "just now");
+
+        clear_test_clock();
+    }
+
+    #[test]
+    fn same_date() {
+        let datetime = DateTimeDefaultNow::<Utc>::default();
+
+        assert!(datetime.same_date(&(datetime - Duration::hours(23))));
+        assert!(!datetime.same_date(&(datetime + Duration::minutes(20))));
+        assert!(!datetime.same_date(&(datetime - Duration::days(1))));
+    }
+
+    #[test]
+    fn same_date_in_own_offset() {
+        let datetime = DateTimeDefaultNow::<Utc>::default();
+        let tokyo = datetime.to_fixed::<9>();
+        let at_zero = DateTimeDefaultNow::<FixedOffset, 9>::from(
+            datetime.with_timezone(&FixedOffset::east_opt(0).unwrap()),
+        );
+
+        assert_eq!(tokyo, at_zero);
+        assert!(tokyo.same_date(&(tokyo + Duration::hours(15))));
+        assert!(!tokyo.same_date(&at_zero));
+    }
+
+    #[test]
+    fn to_utc() {
+        let utc = DateTimeDefaultNow::<Utc>::default();
+        let fixed = DateTimeDefaultNow::<FixedOffset, 9>::default().to_utc();
+        let local = DateTimeDefaultNow::<Local>::from(*utc).to_utc();
+
+        assert_eq!(*fixed, *utc);
+        assert_eq!(fixed.to_rfc3339(), utc.to_rfc3339());
+        assert_eq!(*local, *utc);
+        assert_eq!(local.to_fixed::<9>().to_utc(), utc)
+    }
+
+    #[test]
+    fn add_assign_and_sub_assign() {
+        let default = DateTimeDefaultNow::<FixedOffset, 9>::default();
+        let mut datetime = default;
+
+        datetime += Duration::minutes(5);
+        assert_eq!(datetime, *default + Duration::minutes(5));
+
+        datetime -= Duration::hours(1);
+        assert_eq!(datetime, *default - Duration::minutes(55));
+        assert_eq!(datetime.offset(), default.offset());
+    }
+
+    #[test]
+    fn try_default() {
+        let datetime = DateTimeDefaultNow::<FixedOffset, 9>::try_default().unwrap();
+
+        assert_eq!(datetime, DateTimeDefaultNow::<FixedOffset, 9>::default());
+        assert_eq!(datetime.offset(), &FixedOffset::east_opt(9 * 3600).unwrap());
+        assert!(DateTimeDefaultNow::<FixedOffset, 5, 30>::try_default().is_ok());
+    }
+
+    #[test]
+    fn try_default_out_of_range() {
+        assert_eq!(
+            DateTimeDefaultNow::<FixedOffset, 15>::try_default().unwrap_err(),
+            DefaultError::OffsetOutOfRange {
+                hours: 15,
+                minutes: 0
+            }
+        );
+        assert!(DateTimeDefaultNow::<FixedOffset, -13>::try_default().is_err());
+        assert!(DateTimeDefaultNow::<FixedOffset, 0, 1440>::try_default().is_err());
+    }
+
+    #[test]
+    fn epoch_min_max() {
+        let epoch = DateTimeDefaultNow::<Utc>::epoch();
+
+        assert!(DateTimeDefaultNow::<Utc>::min_value() < epoch);
+        assert!(epoch < DateTimeDefaultNow::<Utc>::max_value());
+        assert_eq!(epoch, DateTime::<Utc>::UNIX_EPOCH);
+        assert_eq!(
+            DateTimeDefaultNow::<Utc>::min_value(),
+            DateTime::<Utc>::MIN_UTC
+        );
+        assert_eq!(
+            DateTimeDefaultNow::<Utc>::max_value(),
+            DateTime::<Utc>::MAX_UTC
+        );
+    }
+
+    #[test]
+    fn epoch_timezones() {
+        assert_eq!(
+            DateTimeDefaultNow::<FixedOffset, 9>::epoch().to_rfc3339(),
+            "1970-01-01T09:00:00+09:00"
+        );
+        assert_eq!(
+            *DateTimeDefaultNow::<Local>::epoch(),
+            DateTime::<Utc>::UNIX_EPOCH
+        );
+        assert_eq!(
+            *DateTimeDefaultNow::<FixedOffset, 9>::max_value(),
+            DateTime::<Utc>::MAX_UTC
+        );
+    }
+
+    #[test]
+    fn cmp_instant() {
+        let at = |s| {
+            DateTimeDefaultNow::<FixedOffset, 9>::from(DateTime::parse_from_rfc3339(s).unwrap())
+        };
+        let mut datetimes = [
+            at("2022-10-11T08:40:00+09:00"),
+            at("2022-10-10T23:39:00+00:00"),
+            at("2022-10-10T18:41:00-05:00"),
+        ];
+
+        datetimes.sort_by(|a, b| a.cmp_instant(b));
+
+        assert_eq!(
+            datetimes
+                .iter()
+                .map(|datetime| datetime.to_rfc3339())
+                .collect::<Vec<_>>(),
+            [
+                "2022-10-10T23:39:00+00:00",
+                "2022-10-11T08:40:00+09:00",
+                "2022-10-10T18:41:00-05:00"
+            ]
+        );
+        assert_eq!(
+            datetimes[0].cmp_instant(&DateTimeDefaultNow::<Utc>::default()),
+            core::cmp::Ordering::Less
+        );
+    }
+
+    #[test]
+    fn now_in_offset() {
+        let instant = DateTime::parse_from_rfc3339("2000-01-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        set_test_clock(FixedClock(instant));
+
+        let datetime = now_in::<9>();
+        assert_eq!(datetime.offset(), &FixedOffset::east_opt(9 * 3600).unwrap());
+        assert_eq!(*datetime, instant);
+        assert_eq!(datetime, DateTimeDefaultNow::<FixedOffset, 9>::default());
+
+        clear_test_clock();
+    }
+
+    #[test]
+    fn elapsed_std() {
+        let now = DateTimeDefaultNow::<Utc>::default();
+        set_test_clock(FixedClock(*now));
+
+        assert_eq!(
+            (now - Duration::milliseconds(1500)).elapsed_std().unwrap(),
+            std::time::Duration::from_millis(1500)
+        );
+        assert_eq!(now.elapsed_std().unwrap(), std::time::Duration::ZERO);
+        assert!((now + Duration::seconds(1)).elapsed_std().is_err());
+
+        clear_test_clock();
+    }
+
+    #[test]
+    fn now_truncated() {
+        let pinned = DateTimeDefaultNow::<Utc>::default();
+        let utc = DateTimeDefaultNow::<Utc>::now_truncated();
+        let fixed = DateTimeDefaultNow::<FixedOffset, 9>::now_truncated();
+        let local = DateTimeDefaultNow::<Local>::now_truncated();
+
+        for datetime in [*utc, fixed.with_timezone(&Utc), local.with_timezone(&Utc)] {
+            assert_eq!(datetime.nanosecond(), 0);
+            assert!(datetime <= *pinned && *pinned - datetime < Duration::seconds(1));
+        }
+        assert_eq!(fixed.offset(), &FixedOffset::east_opt(9 * 3600).unwrap())
+    }
+
+    #[test]
+    fn stable_key() {
+        let utc: DateTimeDefaultNow<FixedOffset> = "2022-10-10T23:40:11Z".parse().unwrap();
+        let tokyo: DateTimeDefaultNow<FixedOffset> = "2022-10-11T08:40:11+09:00".parse().unwrap();
+        let later = utc + Duration::nanoseconds(1);
+
+        assert_eq!(utc.stable_key(), (1_665_445_211_000_000_000, 0));
+        assert_eq!(tokyo.stable_key(), (1_665_445_211_000_000_000, 9 * 3600));
+
+        let mut sorted = [later, tokyo, utc];
+        sorted.sort_by_key(DateTimeDefaultNow::stable_key);
+        let mut reversed = [utc, tokyo, later];
+        reversed.sort_by_key(DateTimeDefaultNow::stable_key);
+
+        assert_eq!(
+            sorted.map(|d| d.stable_key()),
+            reversed.map(|d| d.stable_key())
+        );
+        assert_eq!(
+            sorted.map(|d| d.offset().local_minus_utc()),
+            [0, 9 * 3600, 0]
+        );
+
+        assert_eq!(
+            DateTimeDefaultNow::<Utc>::min_value().stable_key(),
+            (i64::MIN, 0)
+        );
+        assert_eq!(
+            DateTimeDefaultNow::<Utc>::max_value().stable_key(),
+            (i64::MAX, 0)
+        )
+    }
+
+    #[test]
+    fn present_at() {
+        let utc = DateTimeDefaultNow::<Utc>::default();
+        let tokyo = utc.present_at::<9>();
+
+        assert_eq!(tokyo, utc);
+        assert_eq!(tokyo.offset(), &FixedOffset::east_opt(9 * 3600).unwrap());
+        assert_eq!((tokyo.day(), tokyo.hour(), tokyo.minute()), (11, 8, 40));
+        assert_eq!(utc.present_at::<-5>().hour(), 18)
+    }
+
+    #[test]
+    fn signed_duration_since() {
+        let a = DateTimeDefaultNow::<FixedOffset, 9>::default();
+        let b = a - Duration::minutes(90);
+
+        assert_eq!(a.signed_duration_since(&b), a - b);
+        assert_eq!(a.signed_duration_since(&b), Duration::minutes(90));
+        assert_eq!(b.signed_duration_since(&a), b - a);
+        assert_eq!((*a).signed_duration_since(*b), Duration::minutes(90))
+    }
+
+    #[test]
+    fn eq_to_second() {
+        let datetime = DateTimeDefaultNow::<Utc>::default();
+        let captured = datetime.with_nanosecond(1).unwrap();
+
+        assert_ne!(datetime, captured);
+        assert!(datetime.eq_to_second(&captured));
+        assert!(datetime.eq_to_second(&(*datetime.truncated_to_seconds())));
+        assert!(!datetime.eq_to_second(&(captured + Duration::seconds(1))));
+        assert!(
+            !datetime.eq_to_second(&(*datetime.truncated_to_seconds() - Duration::nanoseconds(1)))
+        )
+    }
+
+    #[test]
+    fn zone_label() {
+        assert_eq!(
+            DateTimeDefaultNow::<FixedOffset, 9>::default().zone_label(),
+            "UTC+09:00"
+        );
+        assert_eq!(
+            DateTimeDefaultNow::<FixedOffset, -3, -30>::default().zone_label(),
+            "UTC-03:30"
+        );
+        assert_eq!(DateTimeDefaultNow::<Utc>::default().zone_label(), "UTC");
+
+        let local = DateTimeDefaultNow::<Local>::default();
+        let label = local.zone_label();
+
+        assert_eq!(label, format!("UTC{}", local.offset()));
+        assert_eq!(label.len(), "UTC+09:00".len());
+        assert!(label.starts_with("UTC+") || label.starts_with("UTC-"));
+
+        let epoch = DateTimeDefaultNow::<Local>::from(DateTime::<Utc>::UNIX_EPOCH);
+
+        assert_eq!(epoch.zone_label(), format!("UTC{}", epoch.offset()))
+    }
+
+    #[test]
+    fn from_ymd_hms() {
+        let utc = DateTimeDefaultNow::<Utc>::from_ymd_hms(2022, 10, 10, 23, 40, 11).unwrap();
+        let tokyo =
+            DateTimeDefaultNow::<FixedOffset, 9>::from_ymd_hms(2022, 10, 11, 8, 40, 11).unwrap();
+        let local = DateTimeDefaultNow::<Local>::from_ymd_hms(2022, 10, 10, 12, 0, 0).unwrap();
+
+        assert_eq!(utc.to_rfc3339(), "2022-10-10T23:40:11+00:00");
+        assert_eq!(tokyo.to_rfc3339(), "2022-10-11T08:40:11+09:00");
+        assert_eq!(utc, tokyo);
+        assert_eq!((local.day(), local.hour()), (10, 12));
+
+        assert_eq!(
+            DateTimeDefaultNow::<Utc>::from_ymd_hms(2023, 2, 30, 0, 0, 0),
+            None
+        );
+        assert_eq!(
+            DateTimeDefaultNow::<FixedOffset, 9>::from_ymd_hms(2022, 10, 10, 24, 0, 0),
+            None
+        );
+        assert_eq!(
+            DateTimeDefaultNow::<Local>::from_ymd_hms(2022, 13, 1, 0, 0, 0),
+            None
+        )
+    }
+
+    #[test]
+    fn business_days() {
+        let friday =
+            DateTimeDefaultNow::<FixedOffset, 9>::from_ymd_hms(2022, 10, 14, 8, 40, 11).unwrap();
+        let days: Vec<_> = friday.business_days().take(3).collect();
+
+        assert_eq!(
+            days.iter().map(|d| d.to_rfc3339()).collect::<Vec<_>>(),
+            [
+                "2022-10-14T08:40:11+09:00",
+                "2022-10-17T08:40:11+09:00",
+                "2022-10-18T08:40:11+09:00"
+            ]
+        );
+
+        let saturday = friday + Duration::days(1);
+        assert_eq!(saturday.business_days().next(), Some(days[1]));
+
+        let max = DateTimeDefaultNow::<Utc>::max_value();
+        assert!(max.business_days().count() <= 1)
+    }
+
+    #[test]
+    fn start_and_end_of_day() {
+        let datetime = DateTimeDefaultNow::<FixedOffset, 9>::default();
+        let start = datetime.start_of_day();
+        let end = datetime.end_of_day();
+
+        assert_eq!(
+            (
+                start.day(),
+                start.hour(),
+                start.minute(),
+                start.second(),
+                start.nanosecond()
+            ),
+            (11, 0, 0, 0, 0)
+        );
+        assert_eq!(
+            (
+                end.day(),
+                end.hour(),
+                end.minute(),
+                end.second(),
+                end.nanosecond()
+            ),
+            (11, 23, 59, 59, 999_999_999)
+        );
+        assert_eq!(start.offset(), datetime.offset());
+        assert_eq!(end.offset(), datetime.offset());
+        assert!(start <= datetime && datetime <= end);
+        assert_eq!(end - start, Duration::days(1) - Duration::nanoseconds(1))
+    }
+
+    #[test]
+    fn set_normalizes_offset() {
+        let mut datetime = DateTimeDefaultNow::<FixedOffset, 9>::default();
+        let new = DateTime::parse_from_rfc3339("2000-01-01T00:00:00+00:00").unwrap();
+
+        datetime.set(new);
+
+        assert_eq!(datetime.offset(), &FixedOffset::east_opt(9 * 3600).unwrap());
+        assert_eq!(*datetime, new);
+        assert_eq!(datetime.to_rfc3339(), "2000-01-01T09:00:00+09:00");
+    }
 }
@@ -0,0 +1,73 @@
+#[cfg(feature = "std")]
+use chrono::Local;
+use chrono::{FixedOffset, Utc};
+
+use crate::DateTimeDefaultNow;
+
+/// # UTC DateTime with current time as default.
+///
+/// Alias of [`DateTimeDefaultNow<Utc>`], e.g. for `updated_at: UtcNow` fields.
+///
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use datetime_default::UtcNow;
+///
+/// #[derive(Default)]
+/// struct Article {
+///     updated_at: UtcNow,
+/// }
+///
+/// assert!(Article::default().updated_at <= UtcNow::now());
+/// # }
+/// ```
+pub type UtcNow = DateTimeDefaultNow<Utc>;
+
+/// # Local DateTime with current time as default.
+///
+/// Alias of [`DateTimeDefaultNow<Local>`].
+#[cfg(feature = "std")]
+pub type LocalNow = DateTimeDefaultNow<Local>;
+
+/// # DateTime at a const offset with current time as default.
+///
+/// Alias of [`DateTimeDefaultNow<FixedOffset, OFFSET_HOURS, OFFSET_MINUTES>`].
+///
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use chrono::FixedOffset;
+/// use datetime_default::FixedNow;
+///
+/// assert_eq!(
+///     FixedNow::<5, 30>::default().offset(),
+///     &FixedOffset::east_opt(5 * 3600 + 30 * 60).unwrap()
+/// );
+/// # }
+/// ```
+pub type FixedNow<const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32 = 0> =
+    DateTimeDefaultNow<FixedOffset, OFFSET_HOURS, OFFSET_MINUTES>;
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use chrono::{FixedOffset, Local, Utc};
+
+    use crate::{DateTimeDefaultNow, FixedNow, LocalNow, UtcNow};
+
+    #[derive(Default)]
+    struct Test {
+        created_at: UtcNow,
+        viewed_at: LocalNow,
+        published_at: FixedNow<9>,
+    }
+
+    #[test]
+    fn default_derive_struct() {
+        let test = Test::default();
+
+        assert_eq!(test.created_at, DateTimeDefaultNow::<Utc>::default());
+        assert_eq!(test.viewed_at, DateTimeDefaultNow::<Local>::default());
+        assert_eq!(
+            test.published_at.offset(),
+            &FixedOffset::east_opt(9 * 3600).unwrap()
+        );
+    }
+}
@@ -0,0 +1,223 @@
+use core::ops::Deref;
+
+use chrono::{DateTime, FixedOffset, Utc};
+
+use crate::DateTimeDefaultNow;
+
+/// # DateTime with current time as default, at an offset given in minutes.
+///
+/// `OFFSET_MINUTES` is the total number of minutes east of UTC,
+/// for zones like India (+05:30) without splitting the offset into hours and minutes.
+///
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use chrono::FixedOffset;
+/// use datetime_default::DateTimeDefaultNowMinutes;
+///
+/// assert_eq!(
+///     DateTimeDefaultNowMinutes::<330>::default().offset(),
+///     &FixedOffset::east_opt(5 * 3600 + 30 * 60).unwrap()
+/// );
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DateTimeDefaultNowMinutes<const OFFSET_MINUTES: i32>(DateTime<FixedOffset>);
+
+impl<const OFFSET_MINUTES: i32> DateTimeDefaultNowMinutes<OFFSET_MINUTES> {
+    /// Returns the current time at the configured offset.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `OFFSET_MINUTES` is not strictly within ±1440.
+    #[cfg(feature = "std")]
+    pub fn now() -> Self {
+        Self::from(*DateTimeDefaultNow::<Utc>::now())
+    }
+
+    fn fixed_offset() -> FixedOffset {
+        OFFSET_MINUTES
+            .checked_mul(60)
+            .and_then(FixedOffset::east_opt)
+            .unwrap_or_else(|| {
+                panic!(
+                    "offset {OFFSET_MINUTES} minutes is out of range, \
+                     it must be strictly within ±1440 minutes"
+                )
+            })
+    }
+}
+
+#[cfg(feature = "std")]
+impl<const OFFSET_MINUTES: i32> Default for DateTimeDefaultNowMinutes<OFFSET_MINUTES> {
+    fn default() -> Self {
+        Self::now()
+    }
+}
+
+impl<const OFFSET_MINUTES: i32> Deref for DateTimeDefaultNowMinutes<OFFSET_MINUTES> {
+    type Target = DateTime<FixedOffset>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<const OFFSET_MINUTES: i32> From<DateTime<FixedOffset>>
+    for DateTimeDefaultNowMinutes<OFFSET_MINUTES>
+{
+    fn from(datetime: DateTime<FixedOffset>) -> Self {
+        Self(datetime)
+    }
+}
+
+/// Presents a UTC instant at the configured offset.
+impl<const OFFSET_MINUTES: i32> From<DateTime<Utc>> for DateTimeDefaultNowMinutes<OFFSET_MINUTES> {
+    fn from(datetime: DateTime<Utc>) -> Self {
+        Self(datetime.with_timezone(&Self::fixed_offset()))
+    }
+}
+
+/// Presents the instant at the configured offset in minutes.
+impl<const OFFSET_MINUTES: i32, const OFFSET_HOURS: i32, const HOURS_OFFSET_MINUTES: i32>
+    From<DateTimeDefaultNow<FixedOffset, OFFSET_HOURS, HOURS_OFFSET_MINUTES>>
+    for DateTimeDefaultNowMinutes<OFFSET_MINUTES>
+{
+    fn from(datetime: DateTimeDefaultNow<FixedOffset, OFFSET_HOURS, HOURS_OFFSET_MINUTES>) -> Self {
+        Self::from(datetime.with_timezone(&Utc))
+    }
+}
+
+/// Presents the instant at the configured `OFFSET_HOURS` and `OFFSET_MINUTES`.
+impl<const OFFSET_MINUTES: i32, const OFFSET_HOURS: i32, const HOURS_OFFSET_MINUTES: i32>
+    From<DateTimeDefaultNowMinutes<OFFSET_MINUTES>>
+    for DateTimeDefaultNow<FixedOffset, OFFSET_HOURS, HOURS_OFFSET_MINUTES>
+{
+    fn from(datetime: DateTimeDefaultNowMinutes<OFFSET_MINUTES>) -> Self {
+        Self::from(datetime.with_timezone(&Utc))
+    }
+}
+
+impl<const OFFSET_MINUTES: i32> From<DateTimeDefaultNowMinutes<OFFSET_MINUTES>>
+    for DateTime<FixedOffset>
+{
+    fn from(datetime: DateTimeDefaultNowMinutes<OFFSET_MINUTES>) -> Self {
+        datetime.0
+    }
+}
+
+impl<const OFFSET_MINUTES: i32> core::cmp::PartialEq<DateTime<FixedOffset>>
+    for DateTimeDefaultNowMinutes<OFFSET_MINUTES>
+{
+    fn eq(&self, other: &DateTime<FixedOffset>) -> bool {
+        self.0.eq(other)
+    }
+}
+
+impl<const OFFSET_MINUTES: i32> core::cmp::PartialEq<DateTimeDefaultNowMinutes<OFFSET_MINUTES>>
+    for DateTime<FixedOffset>
+{
+    fn eq(&self, other: &DateTimeDefaultNowMinutes<OFFSET_MINUTES>) -> bool {
+        self.eq(&other.0)
+    }
+}
+
+impl<const OFFSET_MINUTES: i32> core::cmp::PartialOrd<DateTime<FixedOffset>>
+    for DateTimeDefaultNowMinutes<OFFSET_MINUTES>
+{
+    fn partial_cmp(&self, other: &DateTime<FixedOffset>) -> Option<core::cmp::Ordering> {
+        self.0.partial_cmp(other)
+    }
+}
+
+impl<const OFFSET_MINUTES: i32> core::cmp::PartialOrd<DateTimeDefaultNowMinutes<OFFSET_MINUTES>>
+    for DateTime<FixedOffset>
+{
+    fn partial_cmp(
+        &self,
+        other: &DateTimeDefaultNowMinutes<OFFSET_MINUTES>,
+    ) -> Option<core::cmp::Ordering> {
+        self.partial_cmp(&other.0)
+    }
+}
+
+impl<const OFFSET_MINUTES: i32> core::fmt::Display for DateTimeDefaultNowMinutes<OFFSET_MINUTES> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+
+    use chrono::{DateTime, FixedOffset, Utc};
+
+    use crate::{DateTimeDefaultNow, DateTimeDefaultNowMinutes};
+
+    #[derive(Default)]
+    struct Test {
+        updated_at: DateTimeDefaultNowMinutes<330>,
+    }
+
+    #[test]
+    fn default_derive_struct() {
+        Test::default();
+    }
+
+    #[test]
+    fn positive_offset() {
+        let datetime = DateTimeDefaultNowMinutes::<330>::default();
+
+        assert_eq!(
+            datetime.offset(),
+            &FixedOffset::east_opt(5 * 3600 + 30 * 60).unwrap()
+        );
+        assert_eq!(*datetime, *DateTimeDefaultNow::<Utc>::default());
+    }
+
+    #[test]
+    fn negative_offset() {
+        let datetime = DateTimeDefaultNowMinutes::<-570>::default();
+
+        assert_eq!(
+            datetime.offset(),
+            &FixedOffset::west_opt(9 * 3600 + 30 * 60).unwrap()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "offset 1440 minutes is out of range")]
+    fn out_of_range_offset() {
+        DateTimeDefaultNowMinutes::<1440>::default();
+    }
+
+    #[test]
+    fn from_hours_variant() {
+        let hours = DateTimeDefaultNow::<FixedOffset, 9>::default();
+        let minutes = DateTimeDefaultNowMinutes::<540>::from(hours);
+
+        assert_eq!(minutes.offset().local_minus_utc(), 540 * 60);
+        assert_eq!(*minutes, *hours);
+        assert_eq!(minutes.to_rfc3339(), hours.to_rfc3339());
+    }
+
+    #[test]
+    fn into_hours_variant() {
+        let minutes = DateTimeDefaultNowMinutes::<330>::default();
+        let hours = DateTimeDefaultNow::<FixedOffset, 5, 30>::from(minutes);
+        let utc_hours = DateTimeDefaultNow::<FixedOffset>::from(minutes);
+
+        assert_eq!(*hours, *minutes);
+        assert_eq!(hours.offset(), minutes.offset());
+        assert_eq!(*utc_hours, *minutes);
+        assert_eq!(utc_hours.offset().local_minus_utc(), 0);
+    }
+
+    #[test]
+    fn convert_into() {
+        let test = Test::default();
+        let datetime: DateTime<FixedOffset> = test.updated_at.into();
+
+        assert_eq!(datetime, test.updated_at);
+        assert!(test.updated_at <= datetime);
+    }
+}
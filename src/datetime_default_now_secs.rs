@@ -0,0 +1,177 @@
+use core::ops::Deref;
+
+use chrono::{DateTime, FixedOffset, Utc};
+
+#[cfg(feature = "std")]
+use crate::DateTimeDefaultNow;
+
+/// # DateTime with current time as default, at an offset given in seconds.
+///
+/// `OFFSET_SECONDS` is the total number of seconds east of UTC,
+/// for historical zones whose offsets are not whole minutes.
+///
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use chrono::FixedOffset;
+/// use datetime_default::DateTimeDefaultNowSecs;
+///
+/// assert_eq!(
+///     DateTimeDefaultNowSecs::<19800>::default().offset(),
+///     &FixedOffset::east_opt(5 * 3600 + 30 * 60).unwrap()
+/// );
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DateTimeDefaultNowSecs<const OFFSET_SECONDS: i32>(DateTime<FixedOffset>);
+
+impl<const OFFSET_SECONDS: i32> DateTimeDefaultNowSecs<OFFSET_SECONDS> {
+    /// Returns the current time at the configured offset.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `OFFSET_SECONDS` is not strictly within ±86400.
+    #[cfg(feature = "std")]
+    pub fn now() -> Self {
+        Self::from(*DateTimeDefaultNow::<Utc>::now())
+    }
+
+    fn fixed_offset() -> FixedOffset {
+        FixedOffset::east_opt(OFFSET_SECONDS).unwrap_or_else(|| {
+            panic!(
+                "offset {OFFSET_SECONDS} seconds is out of range, \
+                 it must be strictly within ±86400 seconds"
+            )
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl<const OFFSET_SECONDS: i32> Default for DateTimeDefaultNowSecs<OFFSET_SECONDS> {
+    fn default() -> Self {
+        Self::now()
+    }
+}
+
+impl<const OFFSET_SECONDS: i32> Deref for DateTimeDefaultNowSecs<OFFSET_SECONDS> {
+    type Target = DateTime<FixedOffset>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<const OFFSET_SECONDS: i32> From<DateTime<FixedOffset>>
+    for DateTimeDefaultNowSecs<OFFSET_SECONDS>
+{
+    fn from(datetime: DateTime<FixedOffset>) -> Self {
+        Self(datetime)
+    }
+}
+
+/// Presents a UTC instant at the configured offset.
+impl<const OFFSET_SECONDS: i32> From<DateTime<Utc>> for DateTimeDefaultNowSecs<OFFSET_SECONDS> {
+    fn from(datetime: DateTime<Utc>) -> Self {
+        Self(datetime.with_timezone(&Self::fixed_offset()))
+    }
+}
+
+impl<const OFFSET_SECONDS: i32> From<DateTimeDefaultNowSecs<OFFSET_SECONDS>>
+    for DateTime<FixedOffset>
+{
+    fn from(datetime: DateTimeDefaultNowSecs<OFFSET_SECONDS>) -> Self {
+        datetime.0
+    }
+}
+
+impl<const OFFSET_SECONDS: i32> core::cmp::PartialEq<DateTime<FixedOffset>>
+    for DateTimeDefaultNowSecs<OFFSET_SECONDS>
+{
+    fn eq(&self, other: &DateTime<FixedOffset>) -> bool {
+        self.0.eq(other)
+    }
+}
+
+impl<const OFFSET_SECONDS: i32> core::cmp::PartialEq<DateTimeDefaultNowSecs<OFFSET_SECONDS>>
+    for DateTime<FixedOffset>
+{
+    fn eq(&self, other: &DateTimeDefaultNowSecs<OFFSET_SECONDS>) -> bool {
+        self.eq(&other.0)
+    }
+}
+
+impl<const OFFSET_SECONDS: i32> core::cmp::PartialOrd<DateTime<FixedOffset>>
+    for DateTimeDefaultNowSecs<OFFSET_SECONDS>
+{
+    fn partial_cmp(&self, other: &DateTime<FixedOffset>) -> Option<core::cmp::Ordering> {
+        self.0.partial_cmp(other)
+    }
+}
+
+impl<const OFFSET_SECONDS: i32> core::cmp::PartialOrd<DateTimeDefaultNowSecs<OFFSET_SECONDS>>
+    for DateTime<FixedOffset>
+{
+    fn partial_cmp(
+        &self,
+        other: &DateTimeDefaultNowSecs<OFFSET_SECONDS>,
+    ) -> Option<core::cmp::Ordering> {
+        self.partial_cmp(&other.0)
+    }
+}
+
+impl<const OFFSET_SECONDS: i32> core::fmt::Display for DateTimeDefaultNowSecs<OFFSET_SECONDS> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+
+    use chrono::{DateTime, FixedOffset, Utc};
+
+    use crate::{DateTimeDefaultNow, DateTimeDefaultNowSecs};
+
+    #[derive(Default)]
+    struct Test {
+        updated_at: DateTimeDefaultNowSecs<19800>,
+    }
+
+    #[test]
+    fn default_derive_struct() {
+        Test::default();
+    }
+
+    #[test]
+    fn positive_offset() {
+        let datetime = DateTimeDefaultNowSecs::<19800>::default();
+
+        assert_eq!(
+            datetime.offset(),
+            &FixedOffset::east_opt(5 * 3600 + 30 * 60).unwrap()
+        );
+        assert_eq!(*datetime, *DateTimeDefaultNow::<Utc>::default());
+    }
+
+    #[test]
+    fn negative_offset() {
+        let datetime = DateTimeDefaultNowSecs::<-17762>::default();
+
+        assert_eq!(datetime.offset(), &FixedOffset::west_opt(17762).unwrap());
+        assert_eq!(datetime.offset().local_minus_utc(), -17762);
+    }
+
+    #[test]
+    #[should_panic(expected = "offset 86400 seconds is out of range")]
+    fn out_of_range_offset() {
+        DateTimeDefaultNowSecs::<86400>::default();
+    }
+
+    #[test]
+    fn convert_into() {
+        let test = Test::default();
+        let datetime: DateTime<FixedOffset> = test.updated_at.into();
+
+        assert_eq!(datetime, test.updated_at);
+        assert!(test.updated_at <= datetime);
+    }
+}
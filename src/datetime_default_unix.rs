@@ -1,16 +1,20 @@
-use std::ops::Deref;
+use core::ops::Deref;
 
-use chrono::{DateTime, Duration, FixedOffset, Local, TimeZone, Utc};
+#[cfg(feature = "std")]
+use chrono::Local;
+use chrono::{DateTime, Duration, FixedOffset, TimeZone, Utc};
 
 /// # DateTime with UNIX epoch as default.
 ///
 /// ```
-/// use chrono::{Utc, TimeZone};
+/// use chrono::{NaiveDateTime, Utc};
 /// use datetime_default::DateTimeDefaultUnix;
 ///
 /// assert_eq!(
 ///     DateTimeDefaultUnix::<Utc>::default(),
-///     Utc.datetime_from_str("1970/1/1 00:00:00", "%Y/%m/%d %H:%M:%S").unwrap()
+///     NaiveDateTime::parse_from_str("1970/1/1 00:00:00", "%Y/%m/%d %H:%M:%S")
+///         .unwrap()
+///         .and_utc()
 /// );
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -19,24 +23,50 @@ where
     Tz: TimeZone,
     <Tz as TimeZone>::Offset: Copy;
 
+impl<const OFFSET_HOURS: i32> DateTimeDefaultUnix<FixedOffset, OFFSET_HOURS> {
+    /// The default value, usable in `const` and `static` items.
+    pub const VALUE: Self = Self(DateTime::from_naive_utc_and_offset(
+        DateTimeDefaultUnix::<Utc>::VALUE.0.naive_utc(),
+        match FixedOffset::east_opt(OFFSET_HOURS * 3600) {
+            Some(offset) if -12 <= OFFSET_HOURS && OFFSET_HOURS <= 14 => offset,
+            _ => panic!("OFFSET_HOURS must be within -12..=14"),
+        },
+    ));
+}
+
 impl<const OFFSET_HOURS: i32> Default for DateTimeDefaultUnix<FixedOffset, OFFSET_HOURS> {
     fn default() -> Self {
-        Self(
-            DateTimeDefaultUnix::<Utc>::default()
-                .with_timezone(&FixedOffset::east(OFFSET_HOURS * 3600)),
-        )
+        Self::VALUE
     }
 }
 
+#[cfg(feature = "std")]
 impl Default for DateTimeDefaultUnix<Local, 0> {
     fn default() -> Self {
         Self(DateTime::<Local>::default())
     }
 }
 
+impl DateTimeDefaultUnix<Utc, 0> {
+    /// The default value, usable in `const` and `static` items.
+    pub const VALUE: Self = Self(DateTime::<Utc>::UNIX_EPOCH);
+}
+
 impl Default for DateTimeDefaultUnix<Utc, 0> {
     fn default() -> Self {
-        Self(DateTime::<Utc>::default())
+        Self::VALUE
+    }
+}
+
+impl<Tz, const OFFSET_HOURS: i32> DateTimeDefaultUnix<Tz, OFFSET_HOURS>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+    Self: Default,
+{
+    /// Returns `true` if the value is still the UNIX epoch, e.g. for `#[serde(skip_serializing_if)]`.
+    pub fn is_default(&self) -> bool {
+        self.0 == Self::default().0
     }
 }
 
@@ -62,7 +92,9 @@ where
     }
 }
 
-impl<const OFFSET_HOURS: i32> std::str::FromStr for DateTimeDefaultUnix<FixedOffset, OFFSET_HOURS> {
+impl<const OFFSET_HOURS: i32> core::str::FromStr
+    for DateTimeDefaultUnix<FixedOffset, OFFSET_HOURS>
+{
     type Err = chrono::ParseError;
 
     fn from_str(s: &str) -> chrono::ParseResult<DateTimeDefaultUnix<FixedOffset, OFFSET_HOURS>> {
@@ -70,7 +102,8 @@ impl<const OFFSET_HOURS: i32> std::str::FromStr for DateTimeDefaultUnix<FixedOff
     }
 }
 
-impl<const OFFSET_HOURS: i32> std::str::FromStr for DateTimeDefaultUnix<Local, OFFSET_HOURS> {
+#[cfg(feature = "std")]
+impl<const OFFSET_HOURS: i32> core::str::FromStr for DateTimeDefaultUnix<Local, OFFSET_HOURS> {
     type Err = chrono::ParseError;
 
     fn from_str(s: &str) -> chrono::ParseResult<DateTimeDefaultUnix<Local, OFFSET_HOURS>> {
@@ -78,7 +111,7 @@ impl<const OFFSET_HOURS: i32> std::str::FromStr for DateTimeDefaultUnix<Local, O
     }
 }
 
-impl<const OFFSET_HOURS: i32> std::str::FromStr for DateTimeDefaultUnix<Utc, OFFSET_HOURS> {
+impl<const OFFSET_HOURS: i32> core::str::FromStr for DateTimeDefaultUnix<Utc, OFFSET_HOURS> {
     type Err = chrono::ParseError;
 
     fn from_str(s: &str) -> chrono::ParseResult<DateTimeDefaultUnix<Utc, OFFSET_HOURS>> {
@@ -86,7 +119,7 @@ impl<const OFFSET_HOURS: i32> std::str::FromStr for DateTimeDefaultUnix<Utc, OFF
     }
 }
 
-impl<Tz, const OFFSET_HOURS: i32> std::cmp::PartialEq<DateTime<Tz>>
+impl<Tz, const OFFSET_HOURS: i32> core::cmp::PartialEq<DateTime<Tz>>
     for DateTimeDefaultUnix<Tz, OFFSET_HOURS>
 where
     Tz: TimeZone,
@@ -97,7 +130,7 @@ where
     }
 }
 
-impl<Tz, const OFFSET_HOURS: i32> std::cmp::PartialEq<DateTimeDefaultUnix<Tz, OFFSET_HOURS>>
+impl<Tz, const OFFSET_HOURS: i32> core::cmp::PartialEq<DateTimeDefaultUnix<Tz, OFFSET_HOURS>>
     for DateTime<Tz>
 where
     Tz: TimeZone,
@@ -108,18 +141,18 @@ where
     }
 }
 
-impl<Tz, const OFFSET_HOURS: i32> std::cmp::PartialOrd<DateTime<Tz>>
+impl<Tz, const OFFSET_HOURS: i32> core::cmp::PartialOrd<DateTime<Tz>>
     for DateTimeDefaultUnix<Tz, OFFSET_HOURS>
 where
     Tz: TimeZone,
     <Tz as TimeZone>::Offset: Copy,
 {
-    fn partial_cmp(&self, other: &DateTime<Tz>) -> Option<std::cmp::Ordering> {
+    fn partial_cmp(&self, other: &DateTime<Tz>) -> Option<core::cmp::Ordering> {
         self.0.partial_cmp(other)
     }
 }
 
-impl<Tz, const OFFSET_HOURS: i32> std::cmp::PartialOrd<DateTimeDefaultUnix<Tz, OFFSET_HOURS>>
+impl<Tz, const OFFSET_HOURS: i32> core::cmp::PartialOrd<DateTimeDefaultUnix<Tz, OFFSET_HOURS>>
     for DateTime<Tz>
 where
     Tz: TimeZone,
@@ -128,12 +161,12 @@ where
     fn partial_cmp(
         &self,
         other: &DateTimeDefaultUnix<Tz, OFFSET_HOURS>,
-    ) -> Option<std::cmp::Ordering> {
+    ) -> Option<core::cmp::Ordering> {
         self.partial_cmp(&other.0)
     }
 }
 
-impl<Tz, const OFFSET_HOURS: i32> std::ops::Add<Duration> for DateTimeDefaultUnix<Tz, OFFSET_HOURS>
+impl<Tz, const OFFSET_HOURS: i32> core::ops::Add<Duration> for DateTimeDefaultUnix<Tz, OFFSET_HOURS>
 where
     Tz: TimeZone,
     <Tz as TimeZone>::Offset: Copy,
@@ -146,7 +179,7 @@ where
     }
 }
 
-impl<Tz, const OFFSET_HOURS: i32> std::ops::Add<FixedOffset>
+impl<Tz, const OFFSET_HOURS: i32> core::ops::Add<FixedOffset>
     for DateTimeDefaultUnix<Tz, OFFSET_HOURS>
 where
     Tz: TimeZone,
@@ -160,7 +193,7 @@ where
     }
 }
 
-impl<Tz, const OFFSET_HOURS: i32> std::ops::AddAssign<Duration>
+impl<Tz, const OFFSET_HOURS: i32> core::ops::AddAssign<Duration>
     for DateTimeDefaultUnix<Tz, OFFSET_HOURS>
 where
     Tz: TimeZone,
@@ -172,7 +205,7 @@ where
     }
 }
 
-impl<Tz, const OFFSET_HOURS: i32> std::ops::Sub<DateTimeDefaultUnix<Tz, OFFSET_HOURS>>
+impl<Tz, const OFFSET_HOURS: i32> core::ops::Sub<DateTimeDefaultUnix<Tz, OFFSET_HOURS>>
     for DateTimeDefaultUnix<Tz, OFFSET_HOURS>
 where
     Tz: TimeZone,
@@ -186,7 +219,7 @@ where
     }
 }
 
-impl<Tz, const OFFSET_HOURS: i32> std::ops::Sub<DateTime<Tz>>
+impl<Tz, const OFFSET_HOURS: i32> core::ops::Sub<DateTime<Tz>>
     for DateTimeDefaultUnix<Tz, OFFSET_HOURS>
 where
     Tz: TimeZone,
@@ -200,7 +233,7 @@ where
     }
 }
 
-impl<Tz, const OFFSET_HOURS: i32> std::ops::Sub<Duration> for DateTimeDefaultUnix<Tz, OFFSET_HOURS>
+impl<Tz, const OFFSET_HOURS: i32> core::ops::Sub<Duration> for DateTimeDefaultUnix<Tz, OFFSET_HOURS>
 where
     Tz: TimeZone,
     <Tz as TimeZone>::Offset: Copy,
@@ -213,7 +246,7 @@ where
     }
 }
 
-impl<Tz, const OFFSET_HOURS: i32> std::ops::Sub<FixedOffset>
+impl<Tz, const OFFSET_HOURS: i32> core::ops::Sub<FixedOffset>
     for DateTimeDefaultUnix<Tz, OFFSET_HOURS>
 where
     Tz: TimeZone,
@@ -227,7 +260,7 @@ where
     }
 }
 
-impl<Tz, const OFFSET_HOURS: i32> std::ops::SubAssign<Duration>
+impl<Tz, const OFFSET_HOURS: i32> core::ops::SubAssign<Duration>
     for DateTimeDefaultUnix<Tz, OFFSET_HOURS>
 where
     Tz: TimeZone,
@@ -257,6 +290,12 @@ where
             .duration_trunc(duration)
             .map(DateTimeDefaultUnix::from)
     }
+
+    fn duration_round_up(self, duration: Duration) -> Result<Self, Self::Err> {
+        self.0
+            .duration_round_up(duration)
+            .map(DateTimeDefaultUnix::from)
+    }
 }
 
 impl<Tz, const OFFSET_HOURS: i32> chrono::Datelike for DateTimeDefaultUnix<Tz, OFFSET_HOURS>
@@ -382,12 +421,12 @@ where
     }
 }
 
-impl<Tz, const OFFSET_HOURS: i32> std::fmt::Display for DateTimeDefaultUnix<Tz, OFFSET_HOURS>
+impl<Tz, const OFFSET_HOURS: i32> core::fmt::Display for DateTimeDefaultUnix<Tz, OFFSET_HOURS>
 where
     Tz: TimeZone,
-    <Tz as TimeZone>::Offset: Copy + std::fmt::Display,
+    <Tz as TimeZone>::Offset: Copy + core::fmt::Display,
 {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         self.0.fmt(f)
     }
 }
@@ -395,7 +434,9 @@ where
 #[cfg(test)]
 mod tests {
 
-    use chrono::{DateTime, FixedOffset, Local, Utc};
+    #[cfg(feature = "std")]
+    use chrono::Local;
+    use chrono::{DateTime, Duration, FixedOffset, Utc};
 
     use crate::DateTimeDefaultUnix;
 
@@ -434,14 +475,22 @@ mod tests {
         assert_eq!(datetime.to_rfc3339(), "1970-01-01T09:00:00+09:00");
     }
 
+    #[test]
+    fn negative_offset() {
+        let datetime = DateTimeDefaultUnix::<FixedOffset, -5>::default();
+
+        assert_eq!(datetime.to_rfc3339(), "1969-12-31T19:00:00-05:00");
+    }
+
     #[test]
     fn use_datetime_method() {
         let datetime = DateTimeDefaultUnix::<Utc>::default();
 
-        datetime.date();
+        let _ = datetime.date_naive();
     }
 
     #[test]
+    #[cfg(feature = "std")]
     fn convert_from() {
         let now = DateTime::<Local>::default();
         let datetime = DateTimeDefaultUnix::<Local>::from(now);
@@ -450,6 +499,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "std")]
     fn convert_into() {
         let now = DateTime::<Local>::default();
         let datetime: DateTimeDefaultUnix<Local> = now.into();
@@ -458,6 +508,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "std")]
     fn equation1() {
         let now = DateTime::<Local>::default();
         let datetime: DateTimeDefaultUnix<Local> = now.into();
@@ -465,6 +516,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "std")]
     fn equation2() {
         let now = DateTime::<Local>::default();
         let datetime: DateTimeDefaultUnix<Local> = now.into();
@@ -472,12 +524,43 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "std")]
     fn comparing1() {
         assert!(DateTimeDefaultUnix::<Local>::default() <= DateTime::<Local>::default())
     }
 
     #[test]
+    #[cfg(feature = "std")]
     fn comparing2() {
         assert!(DateTime::<Local>::default() <= DateTimeDefaultUnix::<Local>::default())
     }
+
+    const UTC_VALUE: DateTimeDefaultUnix<Utc> = DateTimeDefaultUnix::<Utc>::VALUE;
+    static TOKYO_VALUE: DateTimeDefaultUnix<FixedOffset, 9> =
+        DateTimeDefaultUnix::<FixedOffset, 9>::VALUE;
+
+    #[test]
+    fn const_value() {
+        assert_eq!(UTC_VALUE, DateTimeDefaultUnix::<Utc>::default());
+        assert_eq!(
+            TOKYO_VALUE,
+            DateTimeDefaultUnix::<FixedOffset, 9>::default()
+        );
+        assert_eq!(
+            TOKYO_VALUE.offset(),
+            &FixedOffset::east_opt(9 * 3600).unwrap()
+        )
+    }
+
+    #[test]
+    fn is_default() {
+        assert!(DateTimeDefaultUnix::<Utc>::default().is_default());
+        assert!(DateTimeDefaultUnix::<FixedOffset, 9>::default().is_default());
+        #[cfg(feature = "std")]
+        assert!(DateTimeDefaultUnix::<Local>::default().is_default());
+        assert!(!DateTimeDefaultUnix::<Utc>::from(
+            *DateTimeDefaultUnix::<Utc>::default() + Duration::seconds(1)
+        )
+        .is_default())
+    }
 }
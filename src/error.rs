@@ -0,0 +1,40 @@
+use core::fmt;
+
+/// # Error returned by the fallible default constructors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DefaultError {
+    /// The const offset is outside `-12..=14` hours or not strictly within ±24 hours in total.
+    OffsetOutOfRange { hours: i32, minutes: i32 },
+}
+
+impl fmt::Display for DefaultError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::OffsetOutOfRange { hours, minutes } => {
+                write!(f, "offset {hours} hours {minutes} minutes is out of range")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DefaultError {}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use crate::DefaultError;
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            DefaultError::OffsetOutOfRange {
+                hours: 15,
+                minutes: 0
+            }
+            .to_string(),
+            "offset 15 hours 0 minutes is out of range"
+        );
+    }
+}
@@ -1,2 +1,17 @@
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+#[cfg(feature = "borsh")]
+mod borsh;
+#[cfg(feature = "diesel")]
+mod diesel;
+#[cfg(feature = "rkyv")]
+pub(crate) mod rkyv;
 #[cfg(feature = "schemars")]
 mod schemars;
+#[cfg(feature = "serde_with")]
+mod serde_with;
+#[cfg(feature = "sqlx")]
+mod sqlx;
+
+#[cfg(feature = "serde")]
+pub mod serde;
@@ -0,0 +1,64 @@
+use arbitrary_1::{Arbitrary, Error, Result, Unstructured};
+use chrono::{DateTime, TimeZone, Utc};
+
+use crate::DateTimeDefaultNow;
+
+/// 0001-01-01T00:00:00Z
+const MIN_TIMESTAMP: i64 = -62_135_596_800;
+/// 9999-12-31T23:59:59Z
+const MAX_TIMESTAMP: i64 = 253_402_300_799;
+
+impl<'a, Tz, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> Arbitrary<'a>
+    for DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+    DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>: From<DateTime<Utc>>,
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let secs = u.int_in_range(MIN_TIMESTAMP..=MAX_TIMESTAMP)?;
+        let nsecs = u.int_in_range(0..=999_999_999)?;
+
+        DateTime::from_timestamp(secs, nsecs)
+            .map(DateTimeDefaultNow::from)
+            .ok_or(Error::IncorrectFormat)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <(i64, u32) as Arbitrary>::size_hint(depth)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use arbitrary_1::{Arbitrary, Unstructured};
+    use chrono::{Datelike, FixedOffset, Utc};
+
+    use crate::DateTimeDefaultNow;
+
+    #[test]
+    fn arbitrary_utc() {
+        let bytes = [0x5a; 32];
+        let mut u = Unstructured::new(&bytes);
+        let datetime = DateTimeDefaultNow::<Utc>::arbitrary(&mut u).unwrap();
+
+        assert!((1..=9999).contains(&datetime.year()));
+    }
+
+    #[test]
+    fn arbitrary_fixed_offset() {
+        let bytes = [0xff; 32];
+        let mut u = Unstructured::new(&bytes);
+        let datetime = DateTimeDefaultNow::<FixedOffset, 9>::arbitrary(&mut u).unwrap();
+
+        assert!((1..=10000).contains(&datetime.year()));
+        assert_eq!(datetime.offset(), &FixedOffset::east_opt(9 * 3600).unwrap());
+    }
+
+    #[test]
+    fn arbitrary_empty() {
+        let mut u = Unstructured::new(&[]);
+
+        assert!(DateTimeDefaultNow::<Utc>::arbitrary(&mut u).is_ok());
+    }
+}
@@ -0,0 +1,74 @@
+use std::io::{Error, ErrorKind, Read, Result, Write};
+
+use borsh_1::{BorshDeserialize, BorshSerialize};
+use chrono::{DateTime, TimeZone, Utc};
+
+use crate::DateTimeDefaultNow;
+
+/// Encoded as the UNIX timestamp (`i64`) followed by the sub-second nanoseconds (`u32`).
+impl<Tz, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> BorshSerialize
+    for DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        self.timestamp().serialize(writer)?;
+        self.timestamp_subsec_nanos().serialize(writer)
+    }
+}
+
+/// The offset is not encoded; it is restored from the wrapper type.
+impl<Tz, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> BorshDeserialize
+    for DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+    DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>: From<DateTime<Utc>>,
+{
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        let secs = i64::deserialize_reader(reader)?;
+        let nsecs = u32::deserialize_reader(reader)?;
+
+        DateTime::from_timestamp(secs, nsecs)
+            .map(DateTimeDefaultNow::from)
+            .ok_or_else(|| Error::new(ErrorKind::InvalidData, "timestamp out of range"))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use borsh_1::{from_slice, to_vec};
+    use chrono::{FixedOffset, Utc};
+
+    use crate::DateTimeDefaultNow;
+
+    #[test]
+    fn roundtrip_utc() {
+        let datetime = DateTimeDefaultNow::<Utc>::default();
+        let bytes = to_vec(&datetime).unwrap();
+
+        assert_eq!(bytes.len(), 12);
+        assert_eq!(
+            from_slice::<DateTimeDefaultNow<Utc>>(&bytes).unwrap(),
+            datetime
+        )
+    }
+
+    #[test]
+    fn roundtrip_fixed_offset() {
+        let datetime = DateTimeDefaultNow::<FixedOffset, 9>::default();
+        let decoded =
+            from_slice::<DateTimeDefaultNow<FixedOffset, 9>>(&to_vec(&datetime).unwrap()).unwrap();
+
+        assert_eq!(decoded, datetime);
+        assert_eq!(decoded.offset(), &FixedOffset::east_opt(9 * 3600).unwrap())
+    }
+
+    #[test]
+    fn invalid_nanoseconds() {
+        let bytes = to_vec(&(0i64, 2_000_000_000u32)).unwrap();
+
+        assert!(from_slice::<DateTimeDefaultNow<Utc>>(&bytes).is_err())
+    }
+}
@@ -0,0 +1,76 @@
+use chrono::{DateTime, Utc};
+use diesel_2::{
+    backend::Backend,
+    deserialize::{self, FromSql},
+    serialize::{self, Output, ToSql},
+    sql_types::Timestamptz,
+};
+
+use crate::DateTimeDefaultNow;
+
+impl<DB, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> ToSql<Timestamptz, DB>
+    for DateTimeDefaultNow<Utc, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    DB: Backend,
+    DateTime<Utc>: ToSql<Timestamptz, DB>,
+{
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+        ToSql::<Timestamptz, DB>::to_sql(&**self, out)
+    }
+}
+
+impl<DB, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> FromSql<Timestamptz, DB>
+    for DateTimeDefaultNow<Utc, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    DB: Backend,
+    DateTime<Utc>: FromSql<Timestamptz, DB>,
+{
+    fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+        DateTime::<Utc>::from_sql(bytes).map(DateTimeDefaultNow::from)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::Utc;
+    use diesel_2::{self as diesel, debug_query, pg::Pg, prelude::*};
+
+    use crate::DateTimeDefaultNow;
+
+    diesel::table! {
+        events (id) {
+            id -> Int4,
+            created_at -> Timestamptz,
+        }
+    }
+
+    #[derive(Debug, PartialEq, Queryable, Insertable)]
+    #[diesel(table_name = events)]
+    struct Event {
+        id: i32,
+        created_at: DateTimeDefaultNow<Utc>,
+    }
+
+    #[test]
+    fn insert_query() {
+        let event = Event {
+            id: 1,
+            created_at: DateTimeDefaultNow::default(),
+        };
+        let query = diesel::insert_into(events::table).values(&event);
+
+        assert!(debug_query::<Pg, _>(&query)
+            .to_string()
+            .starts_with("INSERT INTO \"events\" (\"id\", \"created_at\") VALUES ($1, $2)"))
+    }
+
+    #[test]
+    fn filter_query() {
+        let query =
+            events::table.filter(events::created_at.lt(DateTimeDefaultNow::<Utc>::default()));
+
+        assert!(debug_query::<Pg, _>(&query)
+            .to_string()
+            .contains("WHERE (\"events\".\"created_at\" < $1)"))
+    }
+}
@@ -0,0 +1,145 @@
+use std::{fmt, marker::PhantomData};
+
+use chrono::{DateTime, TimeZone, Utc};
+use rkyv_08::{
+    bytecheck::CheckBytes,
+    munge::munge,
+    rancor::{Fallible, Source},
+    Archive, Archived, Deserialize, Place, Portable, Serialize,
+};
+
+use crate::DateTimeDefaultNow;
+
+/// # Archived `DateTimeDefaultNow`.
+///
+/// Stores the UNIX timestamp and sub-second nanoseconds, and keeps the timezone
+/// and const offsets in its type so it deserializes back into the same wrapper.
+#[derive(Portable, CheckBytes)]
+#[rkyv(crate = rkyv_08)]
+#[bytecheck(crate = rkyv_08::bytecheck)]
+#[repr(C)]
+pub struct ArchivedDateTimeDefaultNow<Tz, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> {
+    secs: Archived<i64>,
+    nsecs: Archived<u32>,
+    timezone: PhantomData<Tz>,
+}
+
+impl<Tz, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32>
+    ArchivedDateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>
+{
+    /// Returns the archived UNIX timestamp.
+    pub fn timestamp(&self) -> i64 {
+        self.secs.to_native()
+    }
+
+    /// Returns the archived sub-second nanoseconds.
+    pub fn timestamp_subsec_nanos(&self) -> u32 {
+        self.nsecs.to_native()
+    }
+
+    /// Returns the archived instant in UTC, or `None` if it is out of range.
+    pub fn to_utc(&self) -> Option<DateTime<Utc>> {
+        DateTime::from_timestamp(self.timestamp(), self.timestamp_subsec_nanos())
+    }
+}
+
+impl<Tz, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> Archive
+    for DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+{
+    type Archived = ArchivedDateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>;
+    type Resolver = ();
+
+    fn resolve(&self, _: Self::Resolver, out: Place<Self::Archived>) {
+        munge!(let ArchivedDateTimeDefaultNow { secs, nsecs, timezone } = out);
+        self.timestamp().resolve((), secs);
+        self.timestamp_subsec_nanos().resolve((), nsecs);
+        PhantomData::<Tz>.resolve((), timezone);
+    }
+}
+
+impl<Tz, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32, S> Serialize<S>
+    for DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+    S: Fallible + ?Sized,
+{
+    fn serialize(&self, _: &mut S) -> Result<Self::Resolver, S::Error> {
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+struct TimestampOutOfRange;
+
+impl fmt::Display for TimestampOutOfRange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("archived timestamp is out of range")
+    }
+}
+
+impl std::error::Error for TimestampOutOfRange {}
+
+impl<Tz, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32, D>
+    Deserialize<DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>, D>
+    for ArchivedDateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+    DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>: From<DateTime<Utc>>,
+    D: Fallible + ?Sized,
+    D::Error: Source,
+{
+    fn deserialize(
+        &self,
+        _: &mut D,
+    ) -> Result<DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>, D::Error> {
+        self.to_utc()
+            .map(DateTimeDefaultNow::from)
+            .ok_or_else(|| D::Error::new(TimestampOutOfRange))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{FixedOffset, Utc};
+    use rkyv_08::{access, from_bytes, rancor::Error, to_bytes};
+
+    use super::ArchivedDateTimeDefaultNow;
+    use crate::DateTimeDefaultNow;
+
+    #[test]
+    fn roundtrip_utc() {
+        let datetime = DateTimeDefaultNow::<Utc>::default();
+        let bytes = to_bytes::<Error>(&datetime).unwrap();
+
+        assert_eq!(
+            from_bytes::<DateTimeDefaultNow<Utc>, Error>(&bytes).unwrap(),
+            datetime
+        )
+    }
+
+    #[test]
+    fn access_archived() {
+        let datetime = DateTimeDefaultNow::<Utc>::default();
+        let bytes = to_bytes::<Error>(&datetime).unwrap();
+        let archived = access::<ArchivedDateTimeDefaultNow<Utc, 0, 0>, Error>(&bytes).unwrap();
+
+        assert_eq!(archived.timestamp(), datetime.timestamp());
+        assert_eq!(archived.timestamp_subsec_nanos(), 695_164_300);
+        assert_eq!(archived.to_utc().unwrap(), datetime)
+    }
+
+    #[test]
+    fn roundtrip_fixed_offset() {
+        let datetime = DateTimeDefaultNow::<FixedOffset, 9>::default();
+        let bytes = to_bytes::<Error>(&datetime).unwrap();
+        let decoded = from_bytes::<DateTimeDefaultNow<FixedOffset, 9>, Error>(&bytes).unwrap();
+
+        assert_eq!(decoded, datetime);
+        assert_eq!(decoded.offset(), &FixedOffset::east_opt(9 * 3600).unwrap())
+    }
+}
@@ -3,7 +3,8 @@ use schemars_08::{gen::SchemaGenerator, schema::Schema, JsonSchema};
 
 use crate::DateTimeDefaultNow;
 
-impl<Tz, const OFFSET_HOURS: i32> JsonSchema for DateTimeDefaultNow<Tz, OFFSET_HOURS>
+impl<Tz, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> JsonSchema
+    for DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>
 where
     Tz: TimeZone,
     <Tz as TimeZone>::Offset: Copy,
@@ -19,10 +20,10 @@ where
 
 #[cfg(test)]
 mod test {
-    use chrono::FixedOffset;
+    use chrono::{DateTime, FixedOffset, Utc};
     use schemars::schema_for;
     use schemars_08 as schemars;
-    use serde_json::json;
+    use serde_json_1::json;
 
     use crate::DateTimeDefaultNow;
 
@@ -36,7 +37,7 @@ mod test {
 
         let schema = schema_for!(A);
         assert_eq!(
-            serde_json::to_value(&schema).unwrap(),
+            serde_json_1::to_value(&schema).unwrap(),
             json!(
                 {
                     "$schema": "http://json-schema.org/draft-07/schema#",
@@ -60,4 +61,18 @@ mod test {
             )
         );
     }
+
+    #[test]
+    fn same_schema_as_datetime() {
+        let utc = serde_json_1::to_value(schema_for!(DateTimeDefaultNow<Utc>)).unwrap();
+        let tokyo =
+            serde_json_1::to_value(schema_for!(DateTimeDefaultNow<FixedOffset, 9>)).unwrap();
+
+        assert_eq!(
+            utc,
+            serde_json_1::to_value(schema_for!(DateTime<Utc>)).unwrap()
+        );
+        assert_eq!(utc["format"], "date-time");
+        assert_eq!(tokyo["format"], "date-time")
+    }
 }
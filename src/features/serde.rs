@@ -0,0 +1,687 @@
+//! Serde support and helpers for use with `#[serde(with = "...")]`.
+use chrono::{DateTime, TimeZone};
+use serde_1::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{DateTimeDefaultNow, DateTimeDefaultUnix};
+
+impl<Tz, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> Serialize
+    for DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+    DateTime<Tz>: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        (**self).serialize(serializer)
+    }
+}
+
+impl<'de, Tz, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> Deserialize<'de>
+    for DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+    DateTime<Tz>: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        DateTime::<Tz>::deserialize(deserializer).map(DateTimeDefaultNow::from)
+    }
+}
+
+impl<Tz, const OFFSET_HOURS: i32> Serialize for DateTimeDefaultUnix<Tz, OFFSET_HOURS>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+    DateTime<Tz>: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        (**self).serialize(serializer)
+    }
+}
+
+impl<'de, Tz, const OFFSET_HOURS: i32> Deserialize<'de> for DateTimeDefaultUnix<Tz, OFFSET_HOURS>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+    DateTime<Tz>: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        DateTime::<Tz>::deserialize(deserializer).map(DateTimeDefaultUnix::from)
+    }
+}
+
+/// (De)serialize a `DateTimeDefaultNow` as UNIX seconds.
+///
+/// Deserialized values are shown at the wrapper's timezone and const offset.
+///
+/// ```
+/// # use serde_1 as serde;
+/// use chrono::Utc;
+/// use datetime_default::DateTimeDefaultNow;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// # #[serde(crate = "serde_1")]
+/// struct Event {
+///     #[serde(with = "datetime_default::serde::ts_seconds")]
+///     created_at: DateTimeDefaultNow<Utc>,
+/// }
+/// ```
+pub mod ts_seconds {
+    use chrono::{DateTime, TimeZone, Utc};
+    use serde_1::{Deserializer, Serializer};
+
+    use crate::DateTimeDefaultNow;
+
+    pub fn serialize<Tz, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32, S>(
+        datetime: &DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        Tz: TimeZone,
+        <Tz as TimeZone>::Offset: Copy,
+        S: Serializer,
+    {
+        serializer.serialize_i64(datetime.timestamp())
+    }
+
+    pub fn deserialize<'de, Tz, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32, D>(
+        deserializer: D,
+    ) -> Result<DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>, D::Error>
+    where
+        Tz: TimeZone,
+        <Tz as TimeZone>::Offset: Copy,
+        DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>: From<DateTime<Utc>>,
+        D: Deserializer<'de>,
+    {
+        chrono::serde::ts_seconds::deserialize(deserializer).map(DateTimeDefaultNow::from)
+    }
+}
+
+/// (De)serialize a `DateTimeDefaultNow` as an RFC 3339 string.
+///
+/// Deserialized values are shown at the wrapper's timezone and const offset,
+/// whatever offset the string carries.
+pub mod rfc3339 {
+    use alloc::string::String;
+
+    use chrono::{DateTime, TimeZone, Utc};
+    use serde_1::{de::Error, Deserialize, Deserializer, Serializer};
+
+    use crate::DateTimeDefaultNow;
+
+    pub fn serialize<Tz, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32, S>(
+        datetime: &DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        Tz: TimeZone,
+        <Tz as TimeZone>::Offset: Copy + core::fmt::Display,
+        S: Serializer,
+    {
+        serializer.serialize_str(&datetime.to_rfc3339())
+    }
+
+    pub fn deserialize<'de, Tz, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32, D>(
+        deserializer: D,
+    ) -> Result<DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>, D::Error>
+    where
+        Tz: TimeZone,
+        <Tz as TimeZone>::Offset: Copy,
+        DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>: From<DateTime<Utc>>,
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+
+        DateTime::parse_from_rfc3339(&s)
+            .map(|datetime| DateTimeDefaultNow::from(datetime.with_timezone(&Utc)))
+            .map_err(D::Error::custom)
+    }
+}
+
+/// (De)serialize a `DateTimeDefaultNow` like its default impls, rejecting implausible instants.
+///
+/// Deserialization fails with a serde error when the UTC year is outside
+/// [`MIN_YEAR`](bounded::MIN_YEAR)`..=`[`MAX_YEAR`](bounded::MAX_YEAR),
+/// which catches sentinels such as year 300000 in untrusted input.
+/// Call [`deserialize_within`](bounded::deserialize_within) from your own `deserialize_with` function for another range.
+///
+/// ```
+/// # use serde_1 as serde;
+/// use chrono::Utc;
+/// use datetime_default::DateTimeDefaultNow;
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// # #[serde(crate = "serde_1")]
+/// struct Event {
+///     #[serde(with = "datetime_default::serde::bounded")]
+///     created_at: DateTimeDefaultNow<Utc>,
+/// }
+/// ```
+pub mod bounded {
+    use core::ops::RangeInclusive;
+
+    use chrono::{DateTime, Datelike, TimeZone};
+    use serde_1::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::DateTimeDefaultNow;
+
+    /// Smallest UTC year accepted by [`deserialize`].
+    pub const MIN_YEAR: i32 = 1000;
+    /// Largest UTC year accepted by [`deserialize`].
+    pub const MAX_YEAR: i32 = 9999;
+
+    pub fn serialize<Tz, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32, S>(
+        datetime: &DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        Tz: TimeZone,
+        <Tz as TimeZone>::Offset: Copy,
+        DateTime<Tz>: Serialize,
+        S: Serializer,
+    {
+        datetime.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, Tz, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32, D>(
+        deserializer: D,
+    ) -> Result<DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>, D::Error>
+    where
+        Tz: TimeZone,
+        <Tz as TimeZone>::Offset: Copy,
+        DateTime<Tz>: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        deserialize_within(deserializer, MIN_YEAR..=MAX_YEAR)
+    }
+
+    /// Deserializes like [`deserialize`] but accepts UTC years within `years` instead.
+    pub fn deserialize_within<'de, Tz, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32, D>(
+        deserializer: D,
+        years: RangeInclusive<i32>,
+    ) -> Result<DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>, D::Error>
+    where
+        Tz: TimeZone,
+        <Tz as TimeZone>::Offset: Copy,
+        DateTime<Tz>: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        let datetime =
+            DateTimeDefaultNow::<Tz, OFFSET_HOURS, OFFSET_MINUTES>::deserialize(deserializer)?;
+        let year = datetime.naive_utc().year();
+
+        if years.contains(&year) {
+            Ok(datetime)
+        } else {
+            Err(D::Error::custom(format_args!(
+                "year {year} is outside the accepted range {}..={}",
+                years.start(),
+                years.end()
+            )))
+        }
+    }
+}
+
+/// (De)serialize a `FixedOffset` wrapper as `{ "instant": "...Z", "offset_hours": 9 }`.
+///
+/// The instant is written in UTC, so the offset is only carried by `offset_hours`.
+/// Deserialization fails if `offset_hours` differs from the wrapper's const offset.
+///
+/// ```
+/// # use serde_1 as serde;
+/// use chrono::FixedOffset;
+/// use datetime_default::DateTimeDefaultNow;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// # #[serde(crate = "serde_1")]
+/// struct Event {
+///     #[serde(with = "datetime_default::serde::with_offset")]
+///     created_at: DateTimeDefaultNow<FixedOffset, 9>,
+/// }
+/// ```
+pub mod with_offset {
+    use alloc::string::String;
+    use core::fmt;
+
+    use chrono::{DateTime, FixedOffset, SecondsFormat, Utc};
+    use serde_1::{
+        de::{Error, MapAccess, SeqAccess, Visitor},
+        ser::SerializeStruct,
+        Deserializer, Serializer,
+    };
+
+    use crate::DateTimeDefaultNow;
+
+    const FIELDS: &[&str] = &["instant", "offset_hours"];
+
+    pub fn serialize<const OFFSET_HOURS: i32, S>(
+        datetime: &DateTimeDefaultNow<FixedOffset, OFFSET_HOURS>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let instant = datetime
+            .with_timezone(&Utc)
+            .to_rfc3339_opts(SecondsFormat::AutoSi, true);
+        let mut state = serializer.serialize_struct("WithOffset", 2)?;
+        state.serialize_field("instant", &instant)?;
+        state.serialize_field("offset_hours", &OFFSET_HOURS)?;
+        state.end()
+    }
+
+    pub fn deserialize<'de, const OFFSET_HOURS: i32, D>(
+        deserializer: D,
+    ) -> Result<DateTimeDefaultNow<FixedOffset, OFFSET_HOURS>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_struct("WithOffset", FIELDS, WithOffsetVisitor::<OFFSET_HOURS>)
+    }
+
+    struct WithOffsetVisitor<const OFFSET_HOURS: i32>;
+
+    impl<const OFFSET_HOURS: i32> WithOffsetVisitor<OFFSET_HOURS> {
+        fn build<E: Error>(
+            instant: String,
+            offset_hours: i32,
+        ) -> Result<DateTimeDefaultNow<FixedOffset, OFFSET_HOURS>, E> {
+            if offset_hours != OFFSET_HOURS {
+                return Err(E::custom(format_args!(
+                    "offset_hours {offset_hours} does not match the expected {OFFSET_HOURS}"
+                )));
+            }
+
+            DateTime::parse_from_rfc3339(&instant)
+                .map(|datetime| DateTimeDefaultNow::from(datetime.with_timezone(&Utc)))
+                .map_err(E::custom)
+        }
+    }
+
+    impl<'de, const OFFSET_HOURS: i32> Visitor<'de> for WithOffsetVisitor<OFFSET_HOURS> {
+        type Value = DateTimeDefaultNow<FixedOffset, OFFSET_HOURS>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a struct with `instant` and `offset_hours`")
+        }
+
+        fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: SeqAccess<'de>,
+        {
+            let instant = seq
+                .next_element()?
+                .ok_or_else(|| A::Error::invalid_length(0, &self))?;
+            let offset_hours = seq
+                .next_element()?
+                .ok_or_else(|| A::Error::invalid_length(1, &self))?;
+
+            Self::build(instant, offset_hours)
+        }
+
+        fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+        where
+            A: MapAccess<'de>,
+        {
+            let mut instant = None;
+            let mut offset_hours = None;
+
+            while let Some(key) = map.next_key::<String>()? {
+                match key.as_str() {
+                    "instant" => instant = Some(map.next_value::<String>()?),
+                    "offset_hours" => offset_hours = Some(map.next_value::<i32>()?),
+                    other => return Err(A::Error::unknown_field(other, FIELDS)),
+                }
+            }
+
+            Self::build(
+                instant.ok_or_else(|| A::Error::missing_field("instant"))?,
+                offset_hours.ok_or_else(|| A::Error::missing_field("offset_hours"))?,
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{DateTime, FixedOffset, Local, Utc};
+    use serde_json_1::json;
+
+    use crate::{DateTimeDefaultEpoch, DateTimeDefaultNow, DateTimeDefaultUnix};
+
+    #[test]
+    fn serialize_like_datetime() {
+        let datetime = DateTimeDefaultNow::<Utc>::default();
+
+        assert_eq!(
+            serde_json_1::to_value(datetime).unwrap(),
+            serde_json_1::to_value(*datetime).unwrap()
+        );
+    }
+
+    #[test]
+    fn roundtrip_utc() {
+        let datetime = DateTimeDefaultNow::<Utc>::default();
+        let json = serde_json_1::to_string(&datetime).unwrap();
+
+        assert_eq!(
+            serde_json_1::from_str::<DateTimeDefaultNow<Utc>>(&json).unwrap(),
+            datetime
+        );
+    }
+
+    #[test]
+    fn roundtrip_local() {
+        let datetime = DateTimeDefaultNow::<Local>::default();
+        let json = serde_json_1::to_string(&datetime).unwrap();
+
+        assert_eq!(
+            serde_json_1::from_str::<DateTimeDefaultNow<Local>>(&json).unwrap(),
+            *datetime
+        );
+    }
+
+    #[test]
+    fn roundtrip_fixed_offset() {
+        let datetime = DateTimeDefaultNow::<FixedOffset, 9>::default();
+        let json = serde_json_1::to_string(&datetime).unwrap();
+        let deserialized =
+            serde_json_1::from_str::<DateTimeDefaultNow<FixedOffset, 9>>(&json).unwrap();
+
+        assert_eq!(deserialized, datetime);
+        assert_eq!(
+            deserialized.offset(),
+            &FixedOffset::east_opt(9 * 3600).unwrap()
+        );
+    }
+
+    #[test]
+    fn roundtrip_unix() {
+        let datetime = DateTimeDefaultUnix::<FixedOffset, 9>::default();
+
+        assert_eq!(
+            serde_json_1::to_value(datetime).unwrap(),
+            json!("1970-01-01T09:00:00+09:00")
+        );
+        assert_eq!(
+            serde_json_1::from_value::<DateTimeDefaultUnix<FixedOffset, 9>>(json!(
+                "1970-01-01T09:00:00+09:00"
+            ))
+            .unwrap(),
+            DateTime::<Utc>::default().with_timezone(&FixedOffset::east_opt(9 * 3600).unwrap())
+        );
+    }
+
+    #[test]
+    fn struct_field() {
+        #[derive(Default, serde_1::Serialize, serde_1::Deserialize)]
+        #[serde(crate = "serde_1")]
+        struct A {
+            updated_at: DateTimeDefaultNow<Utc>,
+        }
+
+        let a = A::default();
+        let value = serde_json_1::to_value(&a).unwrap();
+
+        assert_eq!(value, json!({ "updated_at": *a.updated_at }));
+        assert_eq!(
+            serde_json_1::from_value::<A>(value).unwrap().updated_at,
+            a.updated_at
+        );
+    }
+
+    #[test]
+    fn skip_default_epoch() {
+        #[derive(Default, serde_1::Serialize, serde_1::Deserialize)]
+        #[serde(crate = "serde_1")]
+        struct A {
+            #[serde(default, skip_serializing_if = "crate::is_default")]
+            deleted_at: DateTimeDefaultEpoch<Utc>,
+        }
+
+        let unset = A::default();
+        let set = A {
+            deleted_at: DateTimeDefaultEpoch::from(*DateTimeDefaultNow::<Utc>::default()),
+        };
+
+        assert_eq!(serde_json_1::to_value(&unset).unwrap(), json!({}));
+        assert_eq!(
+            serde_json_1::to_value(&set).unwrap(),
+            json!({ "deleted_at": *set.deleted_at })
+        );
+        assert_eq!(
+            serde_json_1::from_value::<A>(json!({})).unwrap().deleted_at,
+            unset.deleted_at
+        );
+    }
+
+    #[test]
+    fn ts_seconds() {
+        #[derive(serde_1::Serialize, serde_1::Deserialize)]
+        #[serde(crate = "serde_1")]
+        struct A {
+            #[serde(with = "crate::serde::ts_seconds")]
+            utc: DateTimeDefaultNow<Utc>,
+            #[serde(with = "crate::serde::ts_seconds")]
+            tokyo: DateTimeDefaultNow<FixedOffset, 9>,
+        }
+
+        let a = A {
+            utc: DateTimeDefaultNow::<Utc>::from_timestamp(1665445211, 0).unwrap(),
+            tokyo: DateTimeDefaultNow::<FixedOffset, 9>::from_timestamp(1665445211, 0).unwrap(),
+        };
+        let value = serde_json_1::to_value(&a).unwrap();
+
+        assert_eq!(value, json!({ "utc": 1665445211, "tokyo": 1665445211 }));
+
+        let a = serde_json_1::from_value::<A>(value).unwrap();
+
+        assert_eq!(a.utc.to_rfc3339(), "2022-10-10T23:40:11+00:00");
+        assert_eq!(a.tokyo.to_rfc3339(), "2022-10-11T08:40:11+09:00");
+    }
+
+    #[test]
+    fn rfc3339() {
+        #[derive(serde_1::Serialize, serde_1::Deserialize)]
+        #[serde(crate = "serde_1")]
+        struct A {
+            #[serde(with = "crate::serde::rfc3339")]
+            utc: DateTimeDefaultNow<Utc>,
+            #[serde(with = "crate::serde::rfc3339")]
+            tokyo: DateTimeDefaultNow<FixedOffset, 9>,
+        }
+
+        let a = A {
+            utc: DateTimeDefaultNow::<Utc>::from_timestamp(1665445211, 0).unwrap(),
+            tokyo: DateTimeDefaultNow::<FixedOffset, 9>::from_timestamp(1665445211, 0).unwrap(),
+        };
+        let value = serde_json_1::to_value(&a).unwrap();
+
+        assert_eq!(
+            value,
+            json!({
+                "utc": "2022-10-10T23:40:11+00:00",
+                "tokyo": "2022-10-11T08:40:11+09:00"
+            })
+        );
+
+        let a = serde_json_1::from_value::<A>(json!({
+            "utc": "2022-10-11T08:40:11+09:00",
+            "tokyo": "2022-10-10T23:40:11Z"
+        }))
+        .unwrap();
+
+        assert_eq!(a.utc.to_rfc3339(), "2022-10-10T23:40:11+00:00");
+        assert_eq!(a.tokyo.to_rfc3339(), "2022-10-11T08:40:11+09:00");
+    }
+
+    #[test]
+    fn rfc3339_invalid() {
+        #[derive(Debug, serde_1::Deserialize)]
+        #[serde(crate = "serde_1")]
+        struct A {
+            #[serde(with = "crate::serde::rfc3339")]
+            #[allow(dead_code)]
+            utc: DateTimeDefaultNow<Utc>,
+        }
+
+        assert!(serde_json_1::from_value::<A>(json!({ "utc": "2022/10/10" })).is_err());
+    }
+
+    #[test]
+    fn bounded() {
+        #[derive(Debug, serde_1::Serialize, serde_1::Deserialize)]
+        #[serde(crate = "serde_1")]
+        struct A {
+            #[serde(with = "crate::serde::bounded")]
+            utc: DateTimeDefaultNow<Utc>,
+        }
+
+        let a = serde_json_1::from_value::<A>(json!({ "utc": "2022-10-10T23:40:11Z" })).unwrap();
+
+        assert_eq!(a.utc.timestamp(), 1665445211);
+        assert_eq!(
+            serde_json_1::to_value(&a).unwrap(),
+            json!({ "utc": "2022-10-10T23:40:11Z" })
+        );
+
+        let error =
+            serde_json_1::from_value::<A>(json!({ "utc": "+200000-01-01T00:00:00Z" })).unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "year 200000 is outside the accepted range 1000..=9999"
+        );
+        assert!(serde_json_1::from_value::<A>(json!({ "utc": "0999-12-31T23:59:59Z" })).is_err());
+    }
+
+    #[test]
+    fn bounded_within() {
+        fn recent<'de, D>(deserializer: D) -> Result<DateTimeDefaultNow<Utc>, D::Error>
+        where
+            D: serde_1::Deserializer<'de>,
+        {
+            crate::serde::bounded::deserialize_within(deserializer, 2000..=2100)
+        }
+
+        #[derive(Debug, serde_1::Deserialize)]
+        #[serde(crate = "serde_1")]
+        struct A {
+            #[serde(deserialize_with = "recent")]
+            #[allow(dead_code)]
+            utc: DateTimeDefaultNow<Utc>,
+        }
+
+        assert!(serde_json_1::from_value::<A>(json!({ "utc": "2022-10-10T23:40:11Z" })).is_ok());
+        assert!(serde_json_1::from_value::<A>(json!({ "utc": "1970-01-01T00:00:00Z" })).is_err());
+    }
+
+    #[test]
+    fn with_offset() {
+        #[derive(Debug, serde_1::Serialize, serde_1::Deserialize)]
+        #[serde(crate = "serde_1")]
+        struct A {
+            #[serde(with = "crate::serde::with_offset")]
+            tokyo: DateTimeDefaultNow<FixedOffset, 9>,
+        }
+
+        let a = A {
+            tokyo: DateTimeDefaultNow::<FixedOffset, 9>::default(),
+        };
+        let value = serde_json_1::to_value(&a).unwrap();
+
+        assert_eq!(
+            value,
+            json!({
+                "tokyo": {
+                    "instant": "2022-10-10T23:40:11.695164300Z",
+                    "offset_hours": 9
+                }
+            })
+        );
+
+        let deserialized = serde_json_1::from_value::<A>(value).unwrap();
+
+        assert_eq!(deserialized.tokyo, a.tokyo);
+        assert_eq!(deserialized.tokyo.offset(), a.tokyo.offset());
+
+        let bytes = bincode_1::serialize(&a).unwrap();
+        assert_eq!(bincode_1::deserialize::<A>(&bytes).unwrap().tokyo, a.tokyo);
+    }
+
+    #[test]
+    fn with_offset_mismatch() {
+        #[derive(Debug, serde_1::Deserialize)]
+        #[serde(crate = "serde_1")]
+        struct A {
+            #[serde(with = "crate::serde::with_offset")]
+            #[allow(dead_code)]
+            tokyo: DateTimeDefaultNow<FixedOffset, 9>,
+        }
+
+        let error = serde_json_1::from_value::<A>(json!({
+            "tokyo": { "instant": "2022-10-10T23:40:11Z", "offset_hours": 0 }
+        }))
+        .unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "offset_hours 0 does not match the expected 9"
+        );
+        assert!(serde_json_1::from_value::<A>(json!({
+            "tokyo": { "instant": "2022-10-10T23:40:11Z" }
+        }))
+        .is_err());
+    }
+
+    #[test]
+    fn roundtrip_bincode() {
+        let utc = DateTimeDefaultNow::<Utc>::default();
+        let fixed = DateTimeDefaultNow::<FixedOffset, 9>::default();
+
+        let bytes = bincode_1::serialize(&utc).unwrap();
+        assert_eq!(
+            bincode_1::deserialize::<DateTimeDefaultNow<Utc>>(&bytes).unwrap(),
+            utc
+        );
+
+        let bytes = bincode_1::serialize(&fixed).unwrap();
+        let deserialized =
+            bincode_1::deserialize::<DateTimeDefaultNow<FixedOffset, 9>>(&bytes).unwrap();
+        assert_eq!(deserialized, fixed);
+        assert_eq!(deserialized.offset(), fixed.offset());
+    }
+
+    #[test]
+    fn roundtrip_postcard() {
+        let utc = DateTimeDefaultNow::<Utc>::default();
+        let fixed = DateTimeDefaultNow::<FixedOffset, 9>::default();
+
+        let bytes = postcard_1::to_allocvec(&utc).unwrap();
+        assert_eq!(
+            postcard_1::from_bytes::<DateTimeDefaultNow<Utc>>(&bytes).unwrap(),
+            utc
+        );
+
+        let bytes = postcard_1::to_allocvec(&fixed).unwrap();
+        let deserialized =
+            postcard_1::from_bytes::<DateTimeDefaultNow<FixedOffset, 9>>(&bytes).unwrap();
+        assert_eq!(deserialized, fixed);
+        assert_eq!(deserialized.offset(), fixed.offset());
+    }
+}
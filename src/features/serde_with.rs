@@ -0,0 +1,80 @@
+use chrono::{DateTime, TimeZone};
+use serde_1::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_with_3::{DeserializeAs, SerializeAs};
+
+use crate::DateTimeDefaultNow;
+
+/// Serializes a plain `DateTime` exactly like the wrapper.
+impl<Tz, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> SerializeAs<DateTime<Tz>>
+    for DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+    DateTime<Tz>: Serialize,
+{
+    fn serialize_as<S>(source: &DateTime<Tz>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        source.serialize(serializer)
+    }
+}
+
+/// Deserializes through the wrapper into a plain `DateTime`.
+impl<'de, Tz, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> DeserializeAs<'de, DateTime<Tz>>
+    for DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+    DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>: Deserialize<'de>,
+{
+    fn deserialize_as<D>(deserializer: D) -> Result<DateTime<Tz>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer).map(DateTime::from)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{DateTime, FixedOffset, Utc};
+    use serde_json_1::json;
+    use serde_with_3::serde_as;
+
+    use crate::DateTimeDefaultNow;
+
+    #[serde_as(crate = "serde_with_3")]
+    #[derive(Debug, PartialEq, serde_1::Serialize, serde_1::Deserialize)]
+    #[serde(crate = "serde_1")]
+    struct Event {
+        #[serde_as(as = "DateTimeDefaultNow<Utc>")]
+        created_at: DateTime<Utc>,
+        #[serde_as(as = "Option<DateTimeDefaultNow<FixedOffset, 9>>")]
+        updated_at: Option<DateTime<FixedOffset>>,
+    }
+
+    #[test]
+    fn serialize_as() {
+        let event = Event {
+            created_at: *DateTimeDefaultNow::<Utc>::default(),
+            updated_at: None,
+        };
+
+        assert_eq!(
+            serde_json_1::to_value(&event).unwrap(),
+            json!({"created_at": "2022-10-10T23:40:11.695164300Z", "updated_at": null})
+        )
+    }
+
+    #[test]
+    fn roundtrip() {
+        let event = Event {
+            created_at: *DateTimeDefaultNow::<Utc>::default(),
+            updated_at: Some(*DateTimeDefaultNow::<FixedOffset, 9>::default()),
+        };
+        let json = serde_json_1::to_string(&event).unwrap();
+
+        assert_eq!(serde_json_1::from_str::<Event>(&json).unwrap(), event)
+    }
+}
@@ -0,0 +1,53 @@
+use chrono::{DateTime, Utc};
+use sqlx_08::{encode::IsNull, error::BoxDynError, Database, Decode, Encode, Type};
+
+use crate::DateTimeDefaultNow;
+
+/// Maps to the same SQL type as `DateTime<Utc>`, e.g. `timestamptz` on Postgres.
+impl<DB, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> Type<DB>
+    for DateTimeDefaultNow<Utc, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    DB: Database,
+    DateTime<Utc>: Type<DB>,
+{
+    fn type_info() -> DB::TypeInfo {
+        <DateTime<Utc> as Type<DB>>::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <DateTime<Utc> as Type<DB>>::compatible(ty)
+    }
+}
+
+impl<'q, DB, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> Encode<'q, DB>
+    for DateTimeDefaultNow<Utc, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    DB: Database,
+    DateTime<Utc>: Encode<'q, DB>,
+{
+    fn encode_by_ref(
+        &self,
+        buf: &mut <DB as Database>::ArgumentBuffer<'q>,
+    ) -> Result<IsNull, BoxDynError> {
+        (**self).encode_by_ref(buf)
+    }
+
+    fn produces(&self) -> Option<DB::TypeInfo> {
+        (**self).produces()
+    }
+
+    fn size_hint(&self) -> usize {
+        (**self).size_hint()
+    }
+}
+
+impl<'r, DB, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> Decode<'r, DB>
+    for DateTimeDefaultNow<Utc, OFFSET_HOURS, OFFSET_MINUTES>
+where
+    DB: Database,
+    DateTime<Utc>: Decode<'r, DB>,
+{
+    fn decode(value: <DB as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+        DateTime::<Utc>::decode(value).map(DateTimeDefaultNow::from)
+    }
+}
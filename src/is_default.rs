@@ -0,0 +1,40 @@
+/// Returns `true` if the value equals `T::default()`.
+///
+/// Meant for `#[serde(skip_serializing_if = "datetime_default::is_default")]` on the fixed-default
+/// wrappers such as [`DateTimeDefaultEpoch`](crate::DateTimeDefaultEpoch).
+/// For [`DateTimeDefaultNow`](crate::DateTimeDefaultNow) the default is a fresh "now",
+/// so the result is practically always `false` and the predicate is meaningless.
+///
+/// ```
+/// use chrono::Utc;
+/// use datetime_default::{is_default, DateTimeDefaultEpoch};
+///
+/// assert!(is_default(&DateTimeDefaultEpoch::<Utc>::default()));
+/// ```
+pub fn is_default<T: Default + PartialEq>(value: &T) -> bool {
+    *value == T::default()
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, FixedOffset, Utc};
+
+    use crate::{is_default, DateTimeDefaultEpoch, DateTimeDefaultMax, DateTimeDefaultMin};
+
+    #[test]
+    fn fixed_defaults() {
+        assert!(is_default(&DateTimeDefaultEpoch::<Utc>::default()));
+        assert!(is_default(
+            &DateTimeDefaultEpoch::<FixedOffset, 9>::default()
+        ));
+        assert!(is_default(&DateTimeDefaultMin::<Utc>::default()));
+        assert!(is_default(&DateTimeDefaultMax::<Utc>::default()));
+    }
+
+    #[test]
+    fn changed_value() {
+        let datetime = *DateTimeDefaultEpoch::<Utc>::default() + Duration::seconds(1);
+
+        assert!(!is_default(&DateTimeDefaultEpoch::<Utc>::from(datetime)));
+    }
+}
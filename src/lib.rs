@@ -11,28 +11,125 @@
 //! DateTime with current time as default.
 //!
 //! ```should_panic
-//! use chrono::{Local, TimeZone};
+//! # #[cfg(feature = "std")] {
+//! use chrono::{Local, NaiveDateTime};
 //! use datetime_default::DateTimeDefaultNow;
 //!
 //! assert_eq!(
 //!     DateTimeDefaultNow::<Local>::default(),
-//!     Local.datetime_from_str("2022/10/10 23:40:11.695164300", "%Y/%m/%d %H:%M:%S%.9f").unwrap()
+//!     NaiveDateTime::parse_from_str("2022/10/10 23:40:11.695164300", "%Y/%m/%d %H:%M:%S%.9f")
+//!         .unwrap()
+//!         .and_local_timezone(Local)
+//!         .unwrap()
 //! );
+//! # }
+//! # #[cfg(not(feature = "std"))]
+//! # panic!("needs the `std` feature");
 //! ```
 //!
 //! ### DateTimeDefaultUnix
 //! DateTime with UNIX epoch as default.
 //!
 //! ```
-//! use chrono::{Utc, TimeZone};
+//! use chrono::{NaiveDateTime, Utc};
 //! use datetime_default::DateTimeDefaultUnix;
 //!
 //! assert_eq!(
 //!     DateTimeDefaultUnix::<Utc>::default(),
-//!     Utc.datetime_from_str("1970/1/1 00:00:00", "%Y/%m/%d %H:%M:%S").unwrap()
+//!     NaiveDateTime::parse_from_str("1970/1/1 00:00:00", "%Y/%m/%d %H:%M:%S")
+//!         .unwrap()
+//!         .and_utc()
+//! );
+//! ```
+//!
+//! ### DateTimeDefaultEpoch
+//! Alias of `DateTimeDefaultUnix`.
+//!
+//! ```
+//! use chrono::{Utc, TimeZone};
+//! use datetime_default::DateTimeDefaultEpoch;
+//!
+//! assert_eq!(DateTimeDefaultEpoch::<Utc>::default(), Utc.timestamp_opt(0, 0).unwrap());
+//! ```
+//!
+//! ### DateTimeDefaultMin
+//! DateTime with minimum representable datetime as default.
+//!
+//! ```
+//! use chrono::{DateTime, Utc};
+//! use datetime_default::DateTimeDefaultMin;
+//!
+//! assert_eq!(DateTimeDefaultMin::<Utc>::default(), DateTime::<Utc>::MIN_UTC);
+//! ```
+//!
+//! ### DateTimeDefaultMax
+//! DateTime with maximum representable datetime as default.
+//!
+//! ```
+//! use chrono::{DateTime, Utc};
+//! use datetime_default::DateTimeDefaultMax;
+//!
+//! assert_eq!(DateTimeDefaultMax::<Utc>::default(), DateTime::<Utc>::MAX_UTC);
+//! ```
+//!
+//! ### DateTimeDefaultFixed
+//! DateTime with a compile-time fixed date at midnight UTC as default.
+//!
+//! ```
+//! use chrono::{TimeZone, Utc};
+//! use datetime_default::DateTimeDefaultFixed;
+//!
+//! assert_eq!(
+//!     DateTimeDefaultFixed::<2022, 10, 10>::default(),
+//!     Utc.with_ymd_and_hms(2022, 10, 10, 0, 0, 0).unwrap()
 //! );
 //! ```
 //!
+//! ### NaiveDateTimeDefaultNow
+//! NaiveDateTime with current UTC wall-clock time as default.
+//!
+//! ```should_panic
+//! # #[cfg(feature = "std")] {
+//! use chrono::NaiveDateTime;
+//! use datetime_default::NaiveDateTimeDefaultNow;
+//!
+//! assert_eq!(
+//!     NaiveDateTimeDefaultNow::default(),
+//!     NaiveDateTime::parse_from_str("2022/10/10 23:40:11.695164300", "%Y/%m/%d %H:%M:%S%.9f").unwrap()
+//! );
+//! # }
+//! # #[cfg(not(feature = "std"))]
+//! # panic!("needs the `std` feature");
+//! ```
+//!
+//! ### DateDefaultToday
+//! NaiveDate with today as default.
+//!
+//! ```should_panic
+//! # #[cfg(feature = "std")] {
+//! use chrono::{NaiveDate, Utc};
+//! use datetime_default::DateDefaultToday;
+//!
+//! assert_eq!(
+//!     DateDefaultToday::<Utc>::default(),
+//!     NaiveDate::from_ymd_opt(2022, 10, 10).unwrap()
+//! );
+//! # }
+//! # #[cfg(not(feature = "std"))]
+//! # panic!("needs the `std` feature");
+//! ```
+//!
+//! ### OffsetDateTimeDefaultNow
+//! `time::OffsetDateTime` with current UTC time as default. Requires the `time` feature.
+//!
+//! ```ignore
+//! use datetime_default::OffsetDateTimeDefaultNow;
+//! use time::OffsetDateTime;
+//!
+//! let datetime = OffsetDateTimeDefaultNow::default();
+//! assert!(datetime <= OffsetDateTime::now_utc());
+//! ```
+//!
 //! ### Default Datetime with TimeZone
 //!
 //! ```
@@ -45,10 +142,97 @@
 //! );
 //! ```
 //!
+//! ### Derive Default for plain DateTime fields
+//! With the `derive` feature, `#[derive(DefaultNow)]` implements `Default` for a struct,
+//! filling `DateTime` fields marked `#[datetime_default]` with the current time.
+//!
+//! ```ignore
+//! use chrono::{DateTime, Utc};
+//! use datetime_default::DefaultNow;
+//!
+//! #[derive(DefaultNow)]
+//! struct Article {
+//!     title: String,
+//!     #[datetime_default]
+//!     created_at: DateTime<Utc>,
+//! }
+//!
+//! assert!(Article::default().created_at <= Utc::now());
+//! ```
+//!
+//! ### no_std
+//! Disable the default `std` feature to build without the standard library.
+//! `Utc` and `FixedOffset` wrappers stay available, while `Local`, the clocks and anything reading "now", such as `now()`, `today()` and the `Default` of the "now" and "today" types, require `std`.
+//!
+//! ```toml
+//! datetime-default = { version = "1", default-features = false }
+//! ```
+//!
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+// The test harness links `std` anyway; this brings `println!` into no_std test builds.
+#[cfg(all(test, not(feature = "std")))]
+#[macro_use]
+extern crate std;
+
+// The diesel derives expand to paths rooted at `diesel`.
+#[cfg(feature = "diesel")]
+extern crate diesel_2 as diesel;
+
+#[cfg(feature = "std")]
+mod clock;
+mod current_time;
+mod date_default_today;
 mod datetime_default;
+mod datetime_default_epoch;
+mod datetime_default_fixed;
+mod datetime_default_max;
+mod datetime_default_min;
 mod datetime_default_now;
+mod datetime_default_now_aliases;
+mod datetime_default_now_minutes;
+mod datetime_default_now_secs;
 mod datetime_default_unix;
+mod error;
 mod features;
+mod is_default;
+mod naive_datetime_default_now;
+#[cfg(feature = "time")]
+mod offset_date_time_default_now;
+mod option_datetime_ext;
+pub mod prelude;
+#[cfg(feature = "test-util")]
+pub mod test_util;
 
+#[cfg(feature = "std")]
+pub use clock::{clear_test_clock, set_test_clock, Clock, FixedClock, MonotonicClock, SystemClock};
+pub use current_time::CurrentTime;
+pub use date_default_today::DateDefaultToday;
+#[cfg(feature = "derive")]
+pub use datetime_default_derive::DefaultNow;
+pub use datetime_default_epoch::DateTimeDefaultEpoch;
+pub use datetime_default_fixed::DateTimeDefaultFixed;
+pub use datetime_default_max::DateTimeDefaultMax;
+pub use datetime_default_min::DateTimeDefaultMin;
+#[cfg(feature = "std")]
+pub use datetime_default_now::now_in;
 pub use datetime_default_now::DateTimeDefaultNow;
+#[cfg(feature = "std")]
+pub use datetime_default_now_aliases::LocalNow;
+pub use datetime_default_now_aliases::{FixedNow, UtcNow};
+pub use datetime_default_now_minutes::DateTimeDefaultNowMinutes;
+pub use datetime_default_now_secs::DateTimeDefaultNowSecs;
 pub use datetime_default_unix::DateTimeDefaultUnix;
+pub use error::DefaultError;
+pub use is_default::is_default;
+pub use naive_datetime_default_now::NaiveDateTimeDefaultNow;
+#[cfg(feature = "time")]
+pub use offset_date_time_default_now::OffsetDateTimeDefaultNow;
+pub use option_datetime_ext::OptionDateTimeExt;
+
+#[cfg(feature = "rkyv")]
+pub use features::rkyv::ArchivedDateTimeDefaultNow;
+#[cfg(feature = "serde")]
+pub use features::serde;
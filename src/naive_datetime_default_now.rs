@@ -0,0 +1,279 @@
+use core::ops::{Deref, DerefMut};
+
+use chrono::{Duration, NaiveDateTime};
+
+#[cfg(feature = "std")]
+use crate::clock;
+#[cfg(all(feature = "std", not(test)))]
+use crate::{clock::SystemClock, Clock};
+
+/// # NaiveDateTime with current UTC wall-clock time as default.
+///
+/// ```should_panic
+/// # #[cfg(feature = "std")] {
+/// use chrono::NaiveDateTime;
+/// use datetime_default::NaiveDateTimeDefaultNow;
+///
+/// assert_eq!(
+///     NaiveDateTimeDefaultNow::default(),
+///     NaiveDateTime::parse_from_str("2022/10/10 23:40:11.695164300", "%Y/%m/%d %H:%M:%S%.9f").unwrap()
+/// );
+/// # }
+/// # #[cfg(not(feature = "std"))]
+/// # panic!("needs the `std` feature");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NaiveDateTimeDefaultNow(NaiveDateTime);
+
+impl NaiveDateTimeDefaultNow {
+    /// Returns the current UTC wall-clock time.
+    #[cfg(all(feature = "std", not(test)))]
+    pub fn now() -> Self {
+        Self(
+            clock::test_clock_now()
+                .unwrap_or_else(|| SystemClock.now_utc())
+                .naive_utc(),
+        )
+    }
+
+    #[cfg(all(feature = "std", test))]
+    pub fn now() -> Self {
+        Self(
+            clock::test_clock_now()
+                .map(|now| now.naive_utc())
+                .unwrap_or_else(clock::test_mode_now),
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl Default for NaiveDateTimeDefaultNow {
+    fn default() -> Self {
+        Self::now()
+    }
+}
+
+impl Deref for NaiveDateTimeDefaultNow {
+    type Target = NaiveDateTime;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for NaiveDateTimeDefaultNow {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl From<NaiveDateTime> for NaiveDateTimeDefaultNow {
+    fn from(datetime: NaiveDateTime) -> Self {
+        Self(datetime)
+    }
+}
+
+impl From<NaiveDateTimeDefaultNow> for NaiveDateTime {
+    fn from(datetime: NaiveDateTimeDefaultNow) -> Self {
+        datetime.0
+    }
+}
+
+impl AsRef<NaiveDateTime> for NaiveDateTimeDefaultNow {
+    fn as_ref(&self) -> &NaiveDateTime {
+        &self.0
+    }
+}
+
+impl core::str::FromStr for NaiveDateTimeDefaultNow {
+    type Err = chrono::ParseError;
+
+    fn from_str(s: &str) -> chrono::ParseResult<NaiveDateTimeDefaultNow> {
+        NaiveDateTime::from_str(s).map(NaiveDateTimeDefaultNow::from)
+    }
+}
+
+impl core::cmp::PartialEq<NaiveDateTime> for NaiveDateTimeDefaultNow {
+    fn eq(&self, other: &NaiveDateTime) -> bool {
+        self.0.eq(other)
+    }
+}
+
+impl core::cmp::PartialEq<NaiveDateTimeDefaultNow> for NaiveDateTime {
+    fn eq(&self, other: &NaiveDateTimeDefaultNow) -> bool {
+        self.eq(&other.0)
+    }
+}
+
+impl core::cmp::PartialOrd<NaiveDateTime> for NaiveDateTimeDefaultNow {
+    fn partial_cmp(&self, other: &NaiveDateTime) -> Option<core::cmp::Ordering> {
+        self.0.partial_cmp(other)
+    }
+}
+
+impl core::cmp::PartialOrd<NaiveDateTimeDefaultNow> for NaiveDateTime {
+    fn partial_cmp(&self, other: &NaiveDateTimeDefaultNow) -> Option<core::cmp::Ordering> {
+        self.partial_cmp(&other.0)
+    }
+}
+
+impl core::ops::Add<Duration> for NaiveDateTimeDefaultNow {
+    type Output = NaiveDateTimeDefaultNow;
+
+    #[inline]
+    fn add(self, rhs: Duration) -> Self::Output {
+        NaiveDateTimeDefaultNow(self.0.add(rhs))
+    }
+}
+
+impl core::ops::AddAssign<Duration> for NaiveDateTimeDefaultNow {
+    #[inline]
+    fn add_assign(&mut self, rhs: Duration) {
+        self.0.add_assign(rhs);
+    }
+}
+
+impl core::ops::Sub<NaiveDateTimeDefaultNow> for NaiveDateTimeDefaultNow {
+    type Output = Duration;
+
+    #[inline]
+    fn sub(self, rhs: NaiveDateTimeDefaultNow) -> Duration {
+        self.0.sub(rhs.0)
+    }
+}
+
+impl core::ops::Sub<NaiveDateTime> for NaiveDateTimeDefaultNow {
+    type Output = Duration;
+
+    #[inline]
+    fn sub(self, rhs: NaiveDateTime) -> Duration {
+        self.0.sub(rhs)
+    }
+}
+
+impl core::ops::Sub<Duration> for NaiveDateTimeDefaultNow {
+    type Output = NaiveDateTimeDefaultNow;
+
+    #[inline]
+    fn sub(self, rhs: Duration) -> Self::Output {
+        NaiveDateTimeDefaultNow(self.0.sub(rhs))
+    }
+}
+
+impl core::ops::SubAssign<Duration> for NaiveDateTimeDefaultNow {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Duration) {
+        self.0.sub_assign(rhs);
+    }
+}
+
+impl core::fmt::Display for NaiveDateTimeDefaultNow {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+
+    use chrono::{Duration, NaiveDateTime, Utc};
+
+    use crate::NaiveDateTimeDefaultNow;
+
+    #[derive(Default)]
+    struct Test {
+        updated_at: NaiveDateTimeDefaultNow,
+    }
+
+    fn print_datetime(datetime: &NaiveDateTime) {
+        println!("{datetime}")
+    }
+
+    #[test]
+    fn default_derive_struct() {
+        Test::default();
+    }
+
+    #[test]
+    fn use_default_derive_struct() {
+        let test = Test::default();
+
+        print_datetime(&test.updated_at)
+    }
+
+    #[test]
+    fn use_default_datetime() {
+        let datetime = NaiveDateTimeDefaultNow::default();
+
+        print_datetime(&datetime)
+    }
+
+    #[test]
+    fn test_mode_now() {
+        assert_eq!(
+            NaiveDateTimeDefaultNow::default(),
+            NaiveDateTime::parse_from_str("2022/10/10 23:40:11.695164300", "%Y/%m/%d %H:%M:%S%.9f")
+                .unwrap()
+        )
+    }
+
+    #[test]
+    fn use_datetime_method() {
+        let datetime = NaiveDateTimeDefaultNow::default();
+
+        let _ = datetime.date();
+    }
+
+    #[test]
+    fn convert_from() {
+        let now = Utc::now().naive_utc();
+        let datetime = NaiveDateTimeDefaultNow::from(now);
+
+        assert_eq!(datetime, now)
+    }
+
+    #[test]
+    fn convert_into() {
+        let now = Utc::now().naive_utc();
+        let datetime: NaiveDateTimeDefaultNow = now.into();
+
+        assert_eq!(datetime, now)
+    }
+
+    #[test]
+    fn equation1() {
+        let now = Utc::now().naive_utc();
+        let datetime: NaiveDateTimeDefaultNow = now.into();
+        assert!(datetime == now)
+    }
+
+    #[test]
+    fn equation2() {
+        let now = Utc::now().naive_utc();
+        let datetime: NaiveDateTimeDefaultNow = now.into();
+        assert!(now == datetime)
+    }
+
+    #[test]
+    fn comparing1() {
+        assert!(NaiveDateTimeDefaultNow::default() <= Utc::now().naive_utc())
+    }
+
+    #[test]
+    fn comparing2() {
+        assert!(
+            NaiveDateTime::parse_from_str("2022/10/10 00:00:00", "%Y/%m/%d %H:%M:%S").unwrap()
+                <= NaiveDateTimeDefaultNow::default()
+        )
+    }
+
+    #[test]
+    fn add_duration() {
+        let datetime = NaiveDateTimeDefaultNow::default();
+
+        assert_eq!(
+            datetime + Duration::hours(1),
+            *datetime + Duration::hours(1)
+        )
+    }
+}
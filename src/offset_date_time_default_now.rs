@@ -0,0 +1,268 @@
+use std::ops::{Deref, DerefMut};
+
+use time_03::{Duration, OffsetDateTime};
+
+use crate::clock;
+
+/// # OffsetDateTime of the `time` crate with current time as default.
+///
+/// ```should_panic
+/// # use time_03 as time;
+/// use datetime_default::OffsetDateTimeDefaultNow;
+/// use time::OffsetDateTime;
+///
+/// assert_eq!(
+///     OffsetDateTimeDefaultNow::default(),
+///     OffsetDateTime::from_unix_timestamp_nanos(1_665_445_211_695_164_300).unwrap()
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct OffsetDateTimeDefaultNow(OffsetDateTime);
+
+impl OffsetDateTimeDefaultNow {
+    /// Returns the current UTC time.
+    #[cfg(not(test))]
+    pub fn now() -> Self {
+        Self(Self::test_clock_now().unwrap_or_else(OffsetDateTime::now_utc))
+    }
+
+    #[cfg(test)]
+    pub fn now() -> Self {
+        Self(Self::test_clock_now().unwrap_or_else(|| {
+            let nanos = clock::test_mode_now()
+                .and_utc()
+                .timestamp_nanos_opt()
+                .unwrap();
+            OffsetDateTime::from_unix_timestamp_nanos(nanos.into()).unwrap()
+        }))
+    }
+
+    fn test_clock_now() -> Option<OffsetDateTime> {
+        clock::test_clock_now()
+            .and_then(|now| now.timestamp_nanos_opt())
+            .and_then(|nanos| OffsetDateTime::from_unix_timestamp_nanos(nanos.into()).ok())
+    }
+}
+
+impl Default for OffsetDateTimeDefaultNow {
+    fn default() -> Self {
+        Self::now()
+    }
+}
+
+impl Deref for OffsetDateTimeDefaultNow {
+    type Target = OffsetDateTime;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for OffsetDateTimeDefaultNow {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl From<OffsetDateTime> for OffsetDateTimeDefaultNow {
+    fn from(datetime: OffsetDateTime) -> Self {
+        Self(datetime)
+    }
+}
+
+impl From<OffsetDateTimeDefaultNow> for OffsetDateTime {
+    fn from(datetime: OffsetDateTimeDefaultNow) -> Self {
+        datetime.0
+    }
+}
+
+impl AsRef<OffsetDateTime> for OffsetDateTimeDefaultNow {
+    fn as_ref(&self) -> &OffsetDateTime {
+        &self.0
+    }
+}
+
+impl std::cmp::PartialEq<OffsetDateTime> for OffsetDateTimeDefaultNow {
+    fn eq(&self, other: &OffsetDateTime) -> bool {
+        self.0.eq(other)
+    }
+}
+
+impl std::cmp::PartialEq<OffsetDateTimeDefaultNow> for OffsetDateTime {
+    fn eq(&self, other: &OffsetDateTimeDefaultNow) -> bool {
+        self.eq(&other.0)
+    }
+}
+
+impl std::cmp::PartialOrd<OffsetDateTime> for OffsetDateTimeDefaultNow {
+    fn partial_cmp(&self, other: &OffsetDateTime) -> Option<std::cmp::Ordering> {
+        self.0.partial_cmp(other)
+    }
+}
+
+impl std::cmp::PartialOrd<OffsetDateTimeDefaultNow> for OffsetDateTime {
+    fn partial_cmp(&self, other: &OffsetDateTimeDefaultNow) -> Option<std::cmp::Ordering> {
+        self.partial_cmp(&other.0)
+    }
+}
+
+impl std::ops::Add<Duration> for OffsetDateTimeDefaultNow {
+    type Output = OffsetDateTimeDefaultNow;
+
+    #[inline]
+    fn add(self, rhs: Duration) -> Self::Output {
+        OffsetDateTimeDefaultNow(self.0.add(rhs))
+    }
+}
+
+impl std::ops::AddAssign<Duration> for OffsetDateTimeDefaultNow {
+    #[inline]
+    fn add_assign(&mut self, rhs: Duration) {
+        self.0.add_assign(rhs);
+    }
+}
+
+impl std::ops::Sub<OffsetDateTimeDefaultNow> for OffsetDateTimeDefaultNow {
+    type Output = Duration;
+
+    #[inline]
+    fn sub(self, rhs: OffsetDateTimeDefaultNow) -> Duration {
+        self.0.sub(rhs.0)
+    }
+}
+
+impl std::ops::Sub<OffsetDateTime> for OffsetDateTimeDefaultNow {
+    type Output = Duration;
+
+    #[inline]
+    fn sub(self, rhs: OffsetDateTime) -> Duration {
+        self.0.sub(rhs)
+    }
+}
+
+impl std::ops::Sub<Duration> for OffsetDateTimeDefaultNow {
+    type Output = OffsetDateTimeDefaultNow;
+
+    #[inline]
+    fn sub(self, rhs: Duration) -> Self::Output {
+        OffsetDateTimeDefaultNow(self.0.sub(rhs))
+    }
+}
+
+impl std::ops::SubAssign<Duration> for OffsetDateTimeDefaultNow {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Duration) {
+        self.0.sub_assign(rhs);
+    }
+}
+
+impl std::fmt::Display for OffsetDateTimeDefaultNow {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use time_03::{Duration, OffsetDateTime, UtcOffset};
+
+    use crate::OffsetDateTimeDefaultNow;
+
+    #[derive(Default)]
+    struct Test {
+        updated_at: OffsetDateTimeDefaultNow,
+    }
+
+    fn print_datetime(datetime: &OffsetDateTime) {
+        println!("{datetime}")
+    }
+
+    #[test]
+    fn default_derive_struct() {
+        Test::default();
+    }
+
+    #[test]
+    fn use_default_derive_struct() {
+        let test = Test::default();
+
+        print_datetime(&test.updated_at)
+    }
+
+    #[test]
+    fn use_default_datetime() {
+        let datetime = OffsetDateTimeDefaultNow::default();
+
+        print_datetime(&datetime)
+    }
+
+    #[test]
+    fn test_mode_now() {
+        let datetime = OffsetDateTimeDefaultNow::default();
+
+        assert_eq!(datetime.unix_timestamp(), 1_665_445_211);
+        assert_eq!(datetime.nanosecond(), 695_164_300);
+        assert_eq!(datetime.offset(), UtcOffset::UTC);
+    }
+
+    #[test]
+    fn use_datetime_method() {
+        let datetime = OffsetDateTimeDefaultNow::default();
+
+        let _ = datetime.date();
+    }
+
+    #[test]
+    fn convert_from() {
+        let now = OffsetDateTime::now_utc();
+        let datetime = OffsetDateTimeDefaultNow::from(now);
+
+        assert_eq!(datetime, now)
+    }
+
+    #[test]
+    fn convert_into() {
+        let now = OffsetDateTime::now_utc();
+        let datetime: OffsetDateTimeDefaultNow = now.into();
+
+        assert_eq!(datetime, now)
+    }
+
+    #[test]
+    fn equation1() {
+        let now = OffsetDateTime::now_utc();
+        let datetime: OffsetDateTimeDefaultNow = now.into();
+        assert!(datetime == now)
+    }
+
+    #[test]
+    fn equation2() {
+        let now = OffsetDateTime::now_utc();
+        let datetime: OffsetDateTimeDefaultNow = now.into();
+        assert!(now == datetime)
+    }
+
+    #[test]
+    fn comparing1() {
+        assert!(OffsetDateTimeDefaultNow::default() <= OffsetDateTime::now_utc())
+    }
+
+    #[test]
+    fn comparing2() {
+        assert!(
+            OffsetDateTime::from_unix_timestamp(1_665_360_000).unwrap()
+                <= OffsetDateTimeDefaultNow::default()
+        )
+    }
+
+    #[test]
+    fn add_duration() {
+        let datetime = OffsetDateTimeDefaultNow::default();
+
+        assert_eq!(
+            datetime + Duration::hours(1),
+            *datetime + Duration::hours(1)
+        )
+    }
+}
@@ -0,0 +1,73 @@
+use chrono::TimeZone;
+
+use crate::DateTimeDefaultNow;
+
+/// # Extension for optional `DateTimeDefaultNow` values.
+///
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use chrono::Utc;
+/// use datetime_default::{DateTimeDefaultNow, OptionDateTimeExt};
+///
+/// let updated_at: Option<DateTimeDefaultNow<Utc>> = None;
+/// assert!(updated_at.unwrap_or_now() <= DateTimeDefaultNow::<Utc>::now());
+/// # }
+/// ```
+pub trait OptionDateTimeExt {
+    type DateTime;
+
+    /// Returns the contained value, or the current time if `None`.
+    fn unwrap_or_now(self) -> Self::DateTime;
+}
+
+impl<Tz, const OFFSET_HOURS: i32, const OFFSET_MINUTES: i32> OptionDateTimeExt
+    for Option<DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>>
+where
+    Tz: TimeZone,
+    <Tz as TimeZone>::Offset: Copy,
+    DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>: Default,
+{
+    type DateTime = DateTimeDefaultNow<Tz, OFFSET_HOURS, OFFSET_MINUTES>;
+
+    fn unwrap_or_now(self) -> Self::DateTime {
+        self.unwrap_or_default()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use chrono::{DateTime, FixedOffset, Utc};
+
+    use crate::{clear_test_clock, set_test_clock, DateTimeDefaultNow, FixedClock};
+
+    use super::OptionDateTimeExt;
+
+    fn instant() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2000-01-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    #[test]
+    fn unwrap_or_now_some() {
+        set_test_clock(FixedClock(instant()));
+
+        let datetime = DateTimeDefaultNow::<Utc>::from(DateTime::<Utc>::UNIX_EPOCH);
+        assert_eq!(Some(datetime).unwrap_or_now(), datetime);
+
+        clear_test_clock();
+    }
+
+    #[test]
+    fn unwrap_or_now_none() {
+        set_test_clock(FixedClock(instant()));
+
+        assert_eq!(None::<DateTimeDefaultNow<Utc>>.unwrap_or_now(), instant());
+
+        let fixed = None::<DateTimeDefaultNow<FixedOffset, 9>>.unwrap_or_now();
+        assert_eq!(*fixed, instant());
+        assert_eq!(fixed.to_rfc3339(), "2000-01-01T09:00:00+09:00");
+
+        clear_test_clock();
+    }
+}
@@ -0,0 +1,46 @@
+//! Common imports in one line.
+//!
+//! Re-exports the wrapper types, their aliases and the chrono timezones they are used with.
+//!
+//! ```
+//! # #[cfg(feature = "std")] {
+//! use datetime_default::prelude::*;
+//!
+//! assert!(DateTimeDefaultEpoch::<Utc>::default() < *UtcNow::default());
+//! # }
+//! ```
+
+#[cfg(feature = "std")]
+pub use chrono::Local;
+pub use chrono::{DateTime, FixedOffset, TimeZone, Utc};
+
+#[cfg(feature = "derive")]
+pub use crate::DefaultNow;
+#[cfg(feature = "time")]
+pub use crate::OffsetDateTimeDefaultNow;
+#[cfg(feature = "std")]
+pub use crate::{
+    DateDefaultToday, DateTimeDefaultNowMinutes, DateTimeDefaultNowSecs, LocalNow,
+    NaiveDateTimeDefaultNow,
+};
+pub use crate::{
+    DateTimeDefaultEpoch, DateTimeDefaultFixed, DateTimeDefaultMax, DateTimeDefaultMin,
+    DateTimeDefaultNow, DateTimeDefaultUnix, FixedNow, OptionDateTimeExt, UtcNow,
+};
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn construct_and_compare() {
+        let now = UtcNow::default();
+        let fixed = FixedNow::<9>::default();
+
+        assert_eq!(now, fixed);
+        assert_eq!(now, Utc.from_utc_datetime(&now.naive_utc()));
+        assert!(DateTimeDefaultUnix::<Utc>::default() < *now);
+        assert!(DateTimeDefaultMin::<FixedOffset>::default() < *fixed);
+        assert_eq!(LocalNow::default(), now)
+    }
+}
@@ -0,0 +1,54 @@
+//! Helpers for testing code built on the wrapper types. Requires the `test-util` feature.
+use core::fmt::Debug;
+
+use serde_1::{de::DeserializeOwned, Serialize};
+
+/// Serializes `value` to JSON and back, asserting the result equals the original.
+///
+/// The wrappers compare by instant, so this checks that no precision or offset is lost.
+///
+/// ```
+/// use chrono::FixedOffset;
+/// use datetime_default::{test_util::assert_serde_roundtrip, DateTimeDefaultNow};
+///
+/// assert_serde_roundtrip(&DateTimeDefaultNow::<FixedOffset, 9>::default());
+/// ```
+#[track_caller]
+pub fn assert_serde_roundtrip<T>(value: &T)
+where
+    T: Serialize + DeserializeOwned + PartialEq + Debug,
+{
+    let json = serde_json_1::to_string(value).expect("failed to serialize");
+    let deserialized: T = serde_json_1::from_str(&json).expect("failed to deserialize");
+
+    assert_eq!(
+        &deserialized, value,
+        "round trip through {json} changed the value"
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{FixedOffset, Utc};
+
+    use super::assert_serde_roundtrip;
+    use crate::{DateTimeDefaultNow, DateTimeDefaultUnix};
+
+    #[test]
+    fn roundtrip_utc() {
+        assert_serde_roundtrip(&DateTimeDefaultNow::<Utc>::default());
+        assert_serde_roundtrip(&DateTimeDefaultUnix::<Utc>::default());
+    }
+
+    #[test]
+    fn roundtrip_fixed_offset() {
+        assert_serde_roundtrip(&DateTimeDefaultNow::<FixedOffset, 9>::default());
+        assert_serde_roundtrip(&DateTimeDefaultUnix::<FixedOffset, 9>::default());
+    }
+
+    #[test]
+    fn roundtrip_option() {
+        assert_serde_roundtrip(&Some(DateTimeDefaultNow::<Utc>::default()));
+        assert_serde_roundtrip(&None::<DateTimeDefaultNow<Utc>>);
+    }
+}
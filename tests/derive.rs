@@ -0,0 +1,42 @@
+#![cfg(feature = "derive")]
+
+use chrono::{DateTime, FixedOffset, Local, Utc};
+use datetime_default::DefaultNow;
+
+#[derive(Debug, DefaultNow)]
+struct Article {
+    title: String,
+    views: u32,
+    #[datetime_default]
+    created_at: DateTime<Utc>,
+    #[datetime_default]
+    updated_at: chrono::DateTime<Local>,
+}
+
+#[derive(Debug, DefaultNow)]
+struct Stamp(
+    #[datetime_default] DateTime<FixedOffset>,
+    Option<DateTime<Utc>>,
+);
+
+#[test]
+fn named_fields() {
+    let before = Utc::now();
+    let article = Article::default();
+    let after = Utc::now();
+
+    assert_eq!(article.title, "");
+    assert_eq!(article.views, 0);
+    assert!(before <= article.created_at && article.created_at <= after);
+    assert!(before <= article.updated_at && article.updated_at <= after);
+}
+
+#[test]
+fn tuple_fields() {
+    let before = Utc::now();
+    let stamp = Stamp::default();
+
+    assert!(before <= stamp.0);
+    assert_eq!(stamp.0.offset(), &FixedOffset::east_opt(0).unwrap());
+    assert_eq!(stamp.1, None);
+}
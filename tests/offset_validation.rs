@@ -0,0 +1,10 @@
+// The UI cases go through `Default`, which reads the clock and needs `std`.
+#![cfg(feature = "std")]
+
+#[test]
+fn offset_validation() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui/offset_in_range.rs");
+    t.compile_fail("tests/ui/offset_out_of_range.rs");
+    t.compile_fail("tests/ui/value_offset_out_of_range.rs");
+}
@@ -0,0 +1,13 @@
+use chrono::FixedOffset;
+use datetime_default::{
+    DateTimeDefaultMax, DateTimeDefaultMin, DateTimeDefaultNow, DateTimeDefaultUnix,
+};
+
+fn main() {
+    DateTimeDefaultNow::<FixedOffset, 14>::default();
+    DateTimeDefaultNow::<FixedOffset, -12>::default();
+    DateTimeDefaultNow::<FixedOffset, 5, 30>::default();
+    let _ = DateTimeDefaultUnix::<FixedOffset, 14>::VALUE;
+    let _ = DateTimeDefaultMin::<FixedOffset, -12>::VALUE;
+    let _ = DateTimeDefaultMax::<FixedOffset, 9>::VALUE;
+}
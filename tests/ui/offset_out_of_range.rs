@@ -0,0 +1,6 @@
+use chrono::FixedOffset;
+use datetime_default::DateTimeDefaultNow;
+
+fn main() {
+    DateTimeDefaultNow::<FixedOffset, 25>::default();
+}
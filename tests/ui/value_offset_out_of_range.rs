@@ -0,0 +1,8 @@
+use chrono::FixedOffset;
+use datetime_default::{DateTimeDefaultMax, DateTimeDefaultMin, DateTimeDefaultUnix};
+
+fn main() {
+    let _ = DateTimeDefaultUnix::<FixedOffset, 20>::VALUE;
+    let _ = DateTimeDefaultMin::<FixedOffset, 20>::VALUE;
+    let _ = DateTimeDefaultMax::<FixedOffset, -13>::VALUE;
+}